@@ -39,9 +39,30 @@ pub const PRICE_DATA_SEED: &[u8] = b"price_data";
 /// 奖励池种子
 pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
 
+/// 职工名册种子
+pub const WORKER_ROSTER_SEED: &[u8] = b"worker_roster";
+
+/// 评估员种子
+pub const EVALUATOR_SEED: &[u8] = b"evaluator";
+
+/// 生产者倍增系数覆盖种子
+pub const MULTIPLIER_OVERRIDE_SEED: &[u8] = b"multiplier_override";
+
+/// 交易记录种子
+pub const TRANSACTION_RECORD_SEED: &[u8] = b"transaction_record";
+
+/// 提货券托管代币账户种子
+pub const VOUCHER_ESCROW_SEED: &[u8] = b"voucher_escrow";
+
+/// 管理员多签提案种子
+pub const ADMIN_PROPOSAL_SEED: &[u8] = b"admin_proposal";
+
 /// 平台权限种子
 pub const PLATFORM_AUTHORITY_SEED: &str = "platform_authority";
 
+/// 平台代币铸币权限种子
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+
 // ================================
 // 倍增系数相关常量
 // ================================
@@ -61,6 +82,9 @@ pub const PRODUCER_REWARD_RATIO: u32 = 70;
 /// 职工奖励比例（30%）
 pub const WORKER_REWARD_RATIO: u32 = 30;
 
+/// 平台奖励比例（30%）
+pub const PLATFORM_REWARD_RATIO: u32 = 30;
+
 /// 比例基数（100%）
 pub const RATIO_BASE: u32 = 100;
 
@@ -70,6 +94,15 @@ pub const MAX_CHAIN_LEVELS: u8 = 5;
 /// 链式倍增每层衰减比例（90%）
 pub const CHAIN_DECAY_RATIO: u32 = 90;
 
+/// 链式倍增第一层上游奖励比例（10%）
+pub const CHAIN_REWARD_RATIO: u32 = 10;
+
+/// 每个生产者职工名册最大人数
+pub const MAX_WORKERS_PER_ROSTER: usize = 10;
+
+/// 单条消费记录最大奖励接收者数量
+pub const MAX_REWARD_RECIPIENTS: usize = 20;
+
 // ================================
 // 评估相关常量
 // ================================
@@ -92,6 +125,31 @@ pub const MIN_EVALUATION_INTERVAL: i64 = 30 * 24 * 60 * 60;
 /// 评估有效期（180天）
 pub const EVALUATION_VALIDITY_PERIOD: i64 = 180 * 24 * 60 * 60;
 
+// ================================
+// 信誉相关常量
+// ================================
+
+/// 信誉评分最大值
+pub const MAX_REPUTATION_SCORE: u8 = 100;
+
+/// 新注册用户的初始信誉评分
+pub const DEFAULT_REPUTATION_SCORE: u8 = 75;
+
+/// 信誉滚动平均窗口（按最近的评分次数计）
+pub const REPUTATION_WINDOW: u64 = 20;
+
+/// 争议败诉扣减的信誉评分
+pub const DISPUTE_REPUTATION_PENALTY: u8 = 10;
+
+/// 默认发行提货券所需的最低信誉评分
+pub const DEFAULT_MIN_ISSUE_REPUTATION: u8 = 60;
+
+/// 默认触发倍增奖励惩罚的信誉评分线
+pub const DEFAULT_REPUTATION_PENALTY_THRESHOLD: u8 = 50;
+
+/// 默认低信誉生产者的倍增奖励发放比例（百分比）
+pub const DEFAULT_REPUTATION_PENALTY_RATIO: u8 = 50;
+
 // ================================
 // 价格监控相关常量
 // ================================
@@ -102,12 +160,18 @@ pub const PRICE_VOLATILITY_THRESHOLD: u32 = 20;
 /// 价格数据最大历史记录数量
 pub const MAX_PRICE_HISTORY_RECORDS: usize = 100;
 
+/// 交易历史查询每页最大记录数（受返回数据1024字节限制）
+pub const HISTORY_PAGE_SIZE: usize = 10;
+
 /// 价格更新最小间隔（5分钟）
 pub const MIN_PRICE_UPDATE_INTERVAL: i64 = 5 * 60;
 
 /// 价格预警阈值（10%）
 pub const PRICE_WARNING_THRESHOLD: u32 = 10;
 
+/// 每个商品类别授权喂价方最大数量
+pub const MAX_PRICE_FEEDERS: usize = 5;
+
 // ================================
 // 提货券相关常量
 // ================================
@@ -156,6 +220,32 @@ pub const MAX_PLATFORM_FEE_RATE: u16 = 500;
 /// 基点基数（10000 = 100%）
 pub const BASIS_POINTS_BASE: u16 = 10000;
 
+// ================================
+// 转账限额相关常量
+// ================================
+
+/// 默认日转账限额
+pub const DEFAULT_DAILY_TRANSFER_LIMIT: u64 = 1_000_000;
+
+/// VIP用户日转账限额
+pub const VIP_DAILY_TRANSFER_LIMIT: u64 = 5_000_000;
+
+/// 企业及政府用户日转账限额
+pub const ENTERPRISE_DAILY_TRANSFER_LIMIT: u64 = 50_000_000;
+
+// ================================
+// 通胀控制相关常量
+// ================================
+
+/// 默认平台代币最大供应量（最小单位）
+pub const DEFAULT_MAX_TOKEN_SUPPLY: u64 = 1_000_000_000_000_000;
+
+/// 默认铸造周期长度
+pub const DEFAULT_MINT_EPOCH_DURATION: i64 = SECONDS_PER_WEEK;
+
+/// 默认每个铸造周期的基础预算（不依赖消费额的部分）
+pub const DEFAULT_BASE_EPOCH_MINT_BUDGET: u64 = 1_000_000_000_000;
+
 // ================================
 // 限制相关常量
 // ================================
@@ -225,6 +315,15 @@ pub const MAX_ADMINS: usize = 10;
 /// 评估员最大数量
 pub const MAX_EVALUATORS: usize = 100;
 
+/// 默认多签审批阈值（平台仅有超级管理员一人时按1计）
+pub const DEFAULT_APPROVAL_THRESHOLD: u8 = 2;
+
+/// 存在多名管理员时的最低审批数量，避免单个密钥独自执行特权操作
+pub const MIN_MULTI_ADMIN_APPROVALS: u8 = 2;
+
+/// 管理员多签提案有效期（7天）
+pub const ADMIN_PROPOSAL_VALIDITY: i64 = 7 * 24 * 60 * 60;
+
 // ================================
 // 业务逻辑相关常量
 // ================================
@@ -238,6 +337,9 @@ pub const DISPUTE_RESOLUTION_TIMEOUT: i64 = 30 * SECONDS_PER_DAY;
 /// 自动结算延迟时间（1天）
 pub const AUTO_SETTLEMENT_DELAY: i64 = SECONDS_PER_DAY;
 
+/// 自动确认时使用的默认质量评分
+pub const DEFAULT_AUTO_SETTLEMENT_QUALITY_SCORE: u8 = 5;
+
 /// 质量评分权重基数
 pub const QUALITY_SCORE_WEIGHT_BASE: u32 = 100;
 
@@ -280,6 +382,15 @@ pub const HASH_SIZE: usize = 32;
 /// 签名大小（64字节）
 pub const SIGNATURE_SIZE: usize = 64;
 
+/// Ed25519公钥大小（32字节）
+pub const ED25519_PUBKEY_SIZE: usize = 32;
+
+/// Ed25519预编译指令数据中签名偏移量表的起始位置（签名数量1字节 + 填充1字节）
+pub const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Ed25519预编译指令数据中单个签名偏移量表的大小（7个u16）
+pub const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+
 // ================================
 // 预言机相关常量
 // ================================
@@ -84,6 +84,9 @@ pub enum SoonShopError {
     
     #[msg("金额无效")]
     InvalidAmount = 6023,
+    
+    #[msg("账户已是最新布局版本")]
+    AccountAlreadyMigrated = 6024,
 
     // ================================
     // 平台管理错误 (6100-6199)
@@ -157,6 +160,30 @@ pub enum SoonShopError {
     
     #[msg("系统暂停中")]
     SystemPaused = 6122,
+    
+    #[msg("没有待接受的超级管理员")]
+    NoPendingSuperAdmin = 6123,
+    
+    #[msg("不是待接受的超级管理员")]
+    NotPendingSuperAdmin = 6124,
+    
+    #[msg("多签审批阈值无效")]
+    InvalidApprovalThreshold = 6125,
+    
+    #[msg("已审批该提案")]
+    ProposalAlreadyApproved = 6126,
+    
+    #[msg("提案审批数量不足")]
+    InsufficientApprovals = 6127,
+    
+    #[msg("提案已过期")]
+    ProposalExpired = 6128,
+    
+    #[msg("提案已执行")]
+    ProposalAlreadyExecuted = 6129,
+    
+    #[msg("提案操作与指令不匹配")]
+    ProposalActionMismatch = 6130,
 
     // ================================
     // 用户管理错误 (6200-6299)
@@ -188,6 +215,51 @@ pub enum SoonShopError {
     
     #[msg("用户操作频率过高")]
     UserOperationTooFrequent = 6208,
+    
+    #[msg("钱包已冻结")]
+    WalletFrozen = 6209,
+    
+    #[msg("钱包已暂停")]
+    WalletSuspended = 6210,
+    
+    #[msg("钱包已关闭")]
+    WalletClosed = 6211,
+    
+    #[msg("钱包不允许发送代币")]
+    WalletSendDisabled = 6212,
+    
+    #[msg("钱包不允许接收代币")]
+    WalletReceiveDisabled = 6213,
+    
+    #[msg("钱包余额不为零")]
+    WalletNotEmpty = 6214,
+    
+    #[msg("钱包状态无效")]
+    InvalidWalletStatus = 6215,
+    
+    #[msg("超过日转账限额")]
+    DailyTransferLimitExceeded = 6216,
+    
+    #[msg("转账限额超过允许上限")]
+    TransferLimitTooHigh = 6217,
+    
+    #[msg("商家未认证")]
+    MerchantNotVerified = 6218,
+    
+    #[msg("用户已拥有该角色")]
+    RoleAlreadyGranted = 6219,
+    
+    #[msg("用户未被授予该角色")]
+    RoleNotGranted = 6220,
+    
+    #[msg("钱包仍有未关闭的交易记录")]
+    WalletHasTransactionRecords = 6221,
+    
+    #[msg("钱包仍有未关闭的提货券额度账户")]
+    WalletHasOpenVoucherClaims = 6222,
+    
+    #[msg("钱包仍有待确认或争议中的消费")]
+    WalletHasOpenConsumptions = 6223,
 
     // ================================
     // 提货券相关错误 (6300-6399)
@@ -234,6 +306,15 @@ pub enum SoonShopError {
     
     #[msg("提货券创建失败")]
     VoucherCreationFailed = 6313,
+    
+    #[msg("提货券不允许转让")]
+    VoucherNotTransferable = 6314,
+    
+    #[msg("转让接收方无效")]
+    InvalidTransferRecipient = 6315,
+    
+    #[msg("提货券额度账户仍有可消费余额")]
+    VoucherClaimNotEmpty = 6316,
 
     // ================================
     // 消费相关错误 (6400-6499)
@@ -265,6 +346,9 @@ pub enum SoonShopError {
     
     #[msg("消费时间无效")]
     InvalidConsumptionTime = 6408,
+    
+    #[msg("消费争议期未结束")]
+    ConsumptionDisputeWindowOpen = 6409,
 
     // ================================
     // 倍增奖励错误 (6500-6599)
@@ -402,4 +486,10 @@ pub enum SoonShopError {
     
     #[msg("Token冻结")]
     TokenFrozen = 6906,
+    
+    #[msg("平台代币已创建")]
+    PlatformTokenAlreadyCreated = 6907,
+    
+    #[msg("Token铸币账户无效")]
+    InvalidTokenMint = 6908,
 } 
\ No newline at end of file
@@ -12,7 +12,8 @@
  */
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn, Transfer, CloseAccount};
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions as sysvar_instructions};
 
 // 声明程序ID (32字节)
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkgEUCNKy8mfn");
@@ -75,13 +76,24 @@ pub mod soonshop_core {
     /**
      * 添加管理员
      * 
-     * 功能：向平台添加新的管理员
+     * 功能：向平台添加新的管理员，需要达到审批阈值的多签提案
      * 权限：仅限超级管理员
      */
     pub fn add_admin(
         ctx: Context<AddAdmin>,
         new_admin: Pubkey,
     ) -> Result<()> {
+        // 检查超级管理员权限
+        if !ctx.accounts.platform_config.is_super_admin(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientSuperAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::AddAdmin { admin: new_admin },
+            &ctx.accounts.platform_config,
+        )?;
+
         let platform_config = &mut ctx.accounts.platform_config;
         platform_config.add_admin(new_admin)?;
 
@@ -98,13 +110,24 @@ pub mod soonshop_core {
     /**
      * 移除管理员
      * 
-     * 功能：从平台移除管理员
+     * 功能：从平台移除管理员，需要达到审批阈值的多签提案
      * 权限：仅限超级管理员
      */
     pub fn remove_admin(
         ctx: Context<RemoveAdmin>,
         admin_to_remove: Pubkey,
     ) -> Result<()> {
+        // 检查超级管理员权限
+        if !ctx.accounts.platform_config.is_super_admin(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientSuperAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::RemoveAdmin { admin: admin_to_remove },
+            &ctx.accounts.platform_config,
+        )?;
+
         let platform_config = &mut ctx.accounts.platform_config;
         platform_config.remove_admin(admin_to_remove)?;
 
@@ -118,6 +141,162 @@ pub mod soonshop_core {
         Ok(())
     }
 
+    /**
+     * 提议转移超级管理员
+     * 
+     * 功能：指定新的超级管理员，需由对方调用accept_super_admin接受后生效；传入None时取消待接受的转移
+     * 权限：仅限超级管理员
+     */
+    pub fn propose_super_admin(
+        ctx: Context<ProposeSuperAdmin>,
+        new_super_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        
+        // 检查超级管理员权限
+        if !platform_config.is_super_admin(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientSuperAdminPrivilege.into());
+        }
+
+        platform_config.propose_super_admin(new_super_admin)?;
+
+        // 发送超级管理员转移提议事件
+        emit!(SuperAdminTransferProposed {
+            current_super_admin: platform_config.super_admin,
+            proposed_super_admin: new_super_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 接受超级管理员
+     * 
+     * 功能：被提议的账户接受超级管理员身份，完成转移
+     * 权限：待接受的超级管理员本人
+     */
+    pub fn accept_super_admin(
+        ctx: Context<AcceptSuperAdmin>,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let new_super_admin = ctx.accounts.new_super_admin.key();
+        
+        let previous_super_admin = platform_config.accept_super_admin(new_super_admin)?;
+
+        // 发送超级管理员转移事件
+        emit!(SuperAdminTransferred {
+            previous_super_admin,
+            new_super_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 创建管理员多签提案
+     * 
+     * 功能：为铸币、解除紧急暂停、提取金库、增删管理员等特权操作创建提案，发起人自动计入审批
+     * 权限：仅限管理员
+     */
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let proposer = ctx.accounts.authority.key();
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&proposer) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let proposal_id = platform_config.proposal_count;
+        ctx.accounts.admin_proposal.initialize(proposal_id, proposer, action.clone())?;
+        platform_config.proposal_count = proposal_id
+            .checked_add(1)
+            .ok_or(SoonShopError::MathOverflow)?;
+
+        // 发送提案创建事件
+        emit!(AdminProposalCreated {
+            proposal: ctx.accounts.admin_proposal.key(),
+            proposal_id,
+            proposer,
+            action,
+            expires_at: ctx.accounts.admin_proposal.expires_at,
+            timestamp: ctx.accounts.admin_proposal.created_at,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 审批管理员多签提案
+     * 
+     * 功能：管理员对未过期、未执行的提案进行审批
+     * 权限：仅限管理员
+     */
+    pub fn approve_admin_proposal(
+        ctx: Context<ApproveAdminProposal>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let approver = ctx.accounts.authority.key();
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&approver) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let admin_proposal = &mut ctx.accounts.admin_proposal;
+        admin_proposal.approve(approver)?;
+
+        // 发送提案审批事件
+        emit!(AdminProposalApproved {
+            proposal: admin_proposal.key(),
+            proposal_id: admin_proposal.id,
+            approver,
+            valid_approvals: admin_proposal.valid_approvals(platform_config) as u8,
+            approval_threshold: platform_config.required_approvals() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 设置多签审批阈值
+     * 
+     * 功能：调整特权操作所需的管理员审批数量，需要达到当前审批阈值的多签提案
+     * 权限：仅限管理员
+     */
+    pub fn set_approval_threshold(
+        ctx: Context<SetApprovalThreshold>,
+        threshold: u8,
+    ) -> Result<()> {
+        // 检查管理员权限
+        if !ctx.accounts.platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::SetApprovalThreshold { threshold },
+            &ctx.accounts.platform_config,
+        )?;
+
+        ctx.accounts.platform_config.set_approval_threshold(threshold)?;
+
+        // 发送审批阈值更新事件
+        emit!(ApprovalThresholdUpdated {
+            approval_threshold: threshold,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
     /**
      * 紧急暂停
      * 
@@ -128,12 +307,19 @@ pub mod soonshop_core {
         ctx: Context<EmergencyPause>,
     ) -> Result<()> {
         let platform_config = &mut ctx.accounts.platform_config;
-        platform_config.emergency_pause()?;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        platform_config.emergency_pause(current_time)?;
 
         // 发送紧急暂停事件
         emit!(EmergencyPaused {
             paused_by: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: current_time,
         });
 
         Ok(())
@@ -142,18 +328,232 @@ pub mod soonshop_core {
     /**
      * 恢复系统
      * 
-     * 功能：修复问题后恢复合约正常功能
+     * 功能：修复问题后恢复合约正常功能，需要达到审批阈值的多签提案
      * 权限：仅限管理员
      */
     pub fn emergency_resume(
         ctx: Context<EmergencyResume>,
     ) -> Result<()> {
+        // 检查管理员权限
+        if !ctx.accounts.platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(&AdminAction::EmergencyResume, &ctx.accounts.platform_config)?;
+
         let platform_config = &mut ctx.accounts.platform_config;
-        platform_config.emergency_resume()?;
+        let current_time = Clock::get()?.unix_timestamp;
+        platform_config.emergency_resume(current_time)?;
 
         // 发送恢复系统事件
         emit!(EmergencyResumed {
             resumed_by: ctx.accounts.authority.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 更新平台参数
+     * 
+     * 功能：调整平台费率、倍增系数范围和奖励分配比例，需要达到审批阈值且绑定参数哈希的多签提案
+     * 权限：仅限管理员
+     */
+    pub fn update_platform_parameters(
+        ctx: Context<UpdatePlatformParameters>,
+        params: PlatformParameters,
+    ) -> Result<()> {
+        // 检查管理员权限
+        if !ctx.accounts.platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::UpdatePlatformParameters { params_hash: params.hash()? },
+            &ctx.accounts.platform_config,
+        )?;
+
+        let platform_config = &mut ctx.accounts.platform_config;
+        platform_config.update_parameters(params)?;
+
+        // 发送参数更新事件
+        emit!(PlatformParametersUpdated {
+            updated_by: ctx.accounts.authority.key(),
+            platform_fee_rate: platform_config.platform_fee_rate,
+            base_multiplier: platform_config.base_multiplier,
+            min_multiplier: platform_config.min_multiplier,
+            max_multiplier: platform_config.max_multiplier,
+            producer_reward_ratio: platform_config.producer_reward_ratio,
+            worker_reward_ratio: platform_config.worker_reward_ratio,
+            platform_reward_ratio: platform_config.platform_reward_ratio,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 设置生产者倍增系数
+     * 
+     * 功能：为特定生产者指定倍增系数，覆盖企业评估结果（仍受平台最小/最大倍增系数约束）
+     * 权限：仅限管理员
+     */
+    pub fn set_producer_multiplier(
+        ctx: Context<SetProducerMultiplier>,
+        producer: Pubkey,
+        multiplier: u8,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        ctx.accounts.multiplier_override.set(
+            producer,
+            multiplier,
+            ctx.accounts.authority.key(),
+            expires_at,
+        )?;
+
+        // 发送倍增系数设置事件
+        emit!(ProducerMultiplierSet {
+            producer,
+            multiplier,
+            expires_at,
+            set_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 清除生产者倍增系数
+     * 
+     * 功能：移除生产者的倍增系数覆盖，恢复按企业评估计算
+     * 权限：仅限管理员
+     */
+    pub fn clear_producer_multiplier(
+        ctx: Context<ClearProducerMultiplier>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 发送倍增系数清除事件
+        emit!(ProducerMultiplierCleared {
+            producer: ctx.accounts.multiplier_override.producer,
+            cleared_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 查询平台统计信息
+     * 
+     * 功能：获取平台配置概览和实时统计数据
+     * 权限：任何人
+     */
+    pub fn get_platform_statistics(
+        ctx: Context<GetPlatformStatistics>,
+    ) -> Result<PlatformStatisticsInfo> {
+        let platform_config = &ctx.accounts.platform_config;
+        let statistics = &platform_config.statistics;
+
+        let config = PlatformConfigView {
+            authority: platform_config.super_admin,
+            base_multiplier: platform_config.base_multiplier,
+            max_multiplier: platform_config.max_multiplier,
+            platform_fee_rate: platform_config.platform_fee_rate,
+            reward_pool: platform_config.reward_pool,
+            is_paused: platform_config.status == PlatformStatus::Paused,
+            is_emergency_paused: platform_config.is_emergency_active(Clock::get()?.unix_timestamp),
+            total_rewards_distributed: statistics.total_rewards_distributed,
+            total_vouchers_issued: statistics.issued_vouchers,
+            total_enterprises: statistics.producer_count as u32,
+            total_transactions: platform_config.total_transactions as u32,
+            total_volume: statistics.total_consumption_amount,
+            admin_count: platform_config.admins.len() as u8,
+            created_at: platform_config.created_at,
+            last_updated: platform_config.updated_at,
+        };
+
+        Ok(PlatformStatisticsInfo {
+            config,
+            statistics: statistics.clone(),
+        })
+    }
+
+    /**
+     * 提取平台金库
+     * 
+     * 功能：从平台金库提取已收取的手续费和平台奖励，需要达到审批阈值的多签提案
+     * 权限：仅限管理员
+     */
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let platform_treasury = &ctx.accounts.platform_treasury;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::WithdrawTreasury {
+                destination: ctx.accounts.destination_token_account.key(),
+                amount,
+            },
+            platform_config,
+        )?;
+
+        // 检查金额和金库余额
+        if amount == 0 {
+            return Err(SoonShopError::InvalidAmount.into());
+        }
+        if platform_treasury.owner != ctx.accounts.platform_authority.key() {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+        if platform_treasury.amount < amount {
+            return Err(SoonShopError::InsufficientFunds.into());
+        }
+
+        // 以平台权限PDA签名转出
+        let authority_bump = [ctx.bumps.platform_authority];
+        let authority_seeds: &[&[u8]] = &[constants::PLATFORM_AUTHORITY_SEED.as_bytes(), &authority_bump];
+        let signer = &[authority_seeds];
+        let transfer_instruction = Transfer {
+            from: platform_treasury.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.platform_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_instruction,
+            signer,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        // 发送金库提取事件
+        emit!(TreasuryWithdrawn {
+            destination: ctx.accounts.destination_token_account.key(),
+            amount,
+            withdrawn_by: ctx.accounts.authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -181,6 +581,31 @@ pub mod soonshop_core {
             ctx.accounts.platform_token_account.key(),
         )?;
 
+        // 已通过认证的用户账户中的用户类型同步到钱包
+        if let Some(user_account) = load_optional_account::<UserAccount>(&ctx.accounts.user_account)? {
+            if user_account.is_verified {
+                user_wallet.user_type = user_account.user_type;
+            }
+        }
+
+        // 按用户类型应用默认日转账限额
+        let platform_config = &mut ctx.accounts.platform_config;
+        let tier_limit = platform_config.transfer_limit_tiers.limit_for(&user_wallet.user_type);
+        user_wallet.apply_transfer_limit_tier(tier_limit);
+
+        // 更新平台统计
+        let total_users = platform_config.statistics.total_users
+            .checked_add(1)
+            .ok_or(SoonShopError::MathOverflow)?;
+        let active_users = platform_config.statistics.active_users
+            .checked_add(1)
+            .ok_or(SoonShopError::MathOverflow)?;
+        platform_config.update_statistics(StatisticsUpdate {
+            total_users: Some(total_users),
+            active_users: Some(active_users),
+            ..Default::default()
+        })?;
+
         // 发送创建钱包事件
         emit!(WalletCreated {
             owner: ctx.accounts.user.key(),
@@ -227,11 +652,11 @@ pub mod soonshop_core {
      * 功能：获取用户的所有收入记录，包括奖励、转账等
      * 权限：用户本人
      */
-    pub fn get_income_history(
-        ctx: Context<GetIncomeHistory>,
-        _start_time: Option<i64>,
-        _end_time: Option<i64>,
-        _limit: Option<u32>,
+    pub fn get_income_history<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetIncomeHistory<'info>>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
     ) -> Result<IncomeHistoryInfo> {
         let user_wallet = &ctx.accounts.user_wallet;
         
@@ -240,13 +665,29 @@ pub mod soonshop_core {
             return Err(SoonShopError::Unauthorized.into());
         }
 
-        // 这里应该查询实际的收入记录，但由于Solana的限制，我们返回统计信息
+        // 按剩余账户传入的交易记录分页查询
+        let page = load_transaction_records(ctx.remaining_accounts, user_wallet, start_time, end_time)?;
+        let limit = history_page_limit(limit);
+        let mut records = Vec::new();
+        let mut next_sequence = None;
+        for record in &page {
+            if let Some(income) = record.as_income_record() {
+                if records.len() == limit {
+                    next_sequence = Some(record.sequence);
+                    break;
+                }
+                records.push(income);
+            }
+        }
+
         let income_info = IncomeHistoryInfo {
             total_income: user_wallet.total_income,
             today_income: user_wallet.statistics.today_income,
             monthly_income: user_wallet.statistics.monthly_income,
             max_single_income: user_wallet.statistics.max_single_income,
-            income_count: user_wallet.transaction_count, // 简化处理
+            income_count: records.len() as u64,
+            records,
+            next_sequence,
         };
 
         Ok(income_info)
@@ -258,11 +699,11 @@ pub mod soonshop_core {
      * 功能：获取用户的所有支出记录，包括消费、转账等
      * 权限：用户本人
      */
-    pub fn get_expense_history(
-        ctx: Context<GetExpenseHistory>,
-        _start_time: Option<i64>,
-        _end_time: Option<i64>,
-        _limit: Option<u32>,
+    pub fn get_expense_history<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetExpenseHistory<'info>>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
     ) -> Result<ExpenseHistoryInfo> {
         let user_wallet = &ctx.accounts.user_wallet;
         
@@ -271,13 +712,29 @@ pub mod soonshop_core {
             return Err(SoonShopError::Unauthorized.into());
         }
 
-        // 返回支出统计信息
+        // 按剩余账户传入的交易记录分页查询
+        let page = load_transaction_records(ctx.remaining_accounts, user_wallet, start_time, end_time)?;
+        let limit = history_page_limit(limit);
+        let mut records = Vec::new();
+        let mut next_sequence = None;
+        for record in &page {
+            if let Some(expense) = record.as_expense_record() {
+                if records.len() == limit {
+                    next_sequence = Some(record.sequence);
+                    break;
+                }
+                records.push(expense);
+            }
+        }
+
         let expense_info = ExpenseHistoryInfo {
             total_expense: user_wallet.total_expense,
             today_expense: user_wallet.statistics.today_expense,
             monthly_expense: user_wallet.statistics.monthly_expense,
             max_single_expense: user_wallet.statistics.max_single_expense,
-            expense_count: user_wallet.transaction_count, // 简化处理
+            expense_count: records.len() as u64,
+            records,
+            next_sequence,
         };
 
         Ok(expense_info)
@@ -289,11 +746,12 @@ pub mod soonshop_core {
      * 功能：获取用户获得的所有奖励记录，包括倍增奖励、推荐奖励等
      * 权限：用户本人
      */
-    pub fn get_reward_history(
-        ctx: Context<GetRewardHistory>,
-        _reward_type: Option<RewardType>,
-        _start_time: Option<i64>,
-        _end_time: Option<i64>,
+    pub fn get_reward_history<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetRewardHistory<'info>>,
+        reward_type: Option<RewardType>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
     ) -> Result<RewardHistoryInfo> {
         let user_wallet = &ctx.accounts.user_wallet;
         
@@ -302,12 +760,32 @@ pub mod soonshop_core {
             return Err(SoonShopError::Unauthorized.into());
         }
 
-        // 返回奖励统计信息
+        // 按剩余账户传入的交易记录分页查询，并按奖励类型过滤
+        let page = load_transaction_records(ctx.remaining_accounts, user_wallet, start_time, end_time)?;
+        let limit = history_page_limit(limit);
+        let mut records = Vec::new();
+        let mut next_sequence = None;
+        for record in &page {
+            let Some(reward) = record.as_reward_record() else {
+                continue;
+            };
+            if reward_type.as_ref().is_some_and(|expected| *expected != reward.reward_type) {
+                continue;
+            }
+            if records.len() == limit {
+                next_sequence = Some(record.sequence);
+                break;
+            }
+            records.push(reward);
+        }
+
         let reward_info = RewardHistoryInfo {
             total_rewards: user_wallet.total_rewards,
             today_rewards: user_wallet.statistics.today_rewards,
             monthly_rewards: user_wallet.statistics.monthly_rewards,
-            reward_count: user_wallet.transaction_count, // 简化处理
+            reward_count: records.len() as u64,
+            records,
+            next_sequence,
         };
 
         Ok(reward_info)
@@ -324,6 +802,9 @@ pub mod soonshop_core {
         amount: u64,
         memo: Option<String>,
     ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
         let sender_wallet = &mut ctx.accounts.sender_wallet;
         let recipient_wallet = &mut ctx.accounts.recipient_wallet;
         
@@ -332,9 +813,25 @@ pub mod soonshop_core {
             return Err(SoonShopError::Unauthorized.into());
         }
 
+        // 检查双方钱包状态
+        sender_wallet.check_can_send()?;
+        recipient_wallet.check_can_receive()?;
+
         // 检查转账限额
         sender_wallet.check_transfer_limit(amount)?;
 
+        // 检查平台金库
+        let platform_treasury = &ctx.accounts.platform_treasury;
+        if platform_treasury.owner != ctx.accounts.platform_authority.key()
+            || platform_treasury.mint != ctx.accounts.sender_token_account.mint
+        {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+
+        // 计算平台手续费，收款方实收扣除手续费后的金额
+        let fee = ctx.accounts.platform_config.calculate_fee(amount)?;
+        let net_amount = amount - fee;
+
         // 执行代币转账
         let transfer_instruction = Transfer {
             from: ctx.accounts.sender_token_account.to_account_info(),
@@ -347,17 +844,58 @@ pub mod soonshop_core {
             transfer_instruction,
         );
 
-        token::transfer(cpi_ctx, amount)?;
+        token::transfer(cpi_ctx, net_amount)?;
+
+        // 收取平台手续费
+        if fee > 0 {
+            let fee_instruction = Transfer {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: platform_treasury.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                fee_instruction,
+            );
+            token::transfer(cpi_ctx, fee)?;
+        }
 
         // 更新钱包统计
         sender_wallet.add_expense(amount)?;
-        recipient_wallet.add_income(amount)?;
+        recipient_wallet.add_income(net_amount)?;
+
+        // 写入双方交易记录
+        let token_mint = ctx.accounts.sender_token_account.mint;
+        ctx.accounts.sender_record.record(sender_wallet, TransactionEntry {
+            transaction_type: TransactionType::TransferSent,
+            amount,
+            token_mint,
+            counterparty: Some(recipient_wallet.owner),
+            reward_type: None,
+            description: String::new(),
+        })?;
+        ctx.accounts.recipient_record.record(recipient_wallet, TransactionEntry {
+            transaction_type: TransactionType::TransferReceived,
+            amount: net_amount,
+            token_mint,
+            counterparty: Some(sender_wallet.owner),
+            reward_type: None,
+            description: String::new(),
+        })?;
+
+        // 更新平台统计
+        ctx.accounts.platform_config.update_statistics(StatisticsUpdate {
+            platform_revenue_delta: Some(fee),
+            transactions_delta: Some(1),
+            ..Default::default()
+        })?;
 
         // 发送转账事件
         emit!(TokenTransferred {
             from: ctx.accounts.sender.key(),
             to: ctx.accounts.recipient_wallet.owner,
             amount,
+            fee,
             memo: memo.clone(),
             timestamp: Clock::get()?.unix_timestamp,
         });
@@ -365,50 +903,50 @@ pub mod soonshop_core {
         Ok(())
     }
 
-    // ================================
-    // 代币发行功能
-    // ================================
-
     /**
-     * 创建平台代币
+     * 设置用户类型
      * 
-     * 功能：创建用于提货券额度、奖励分发的平台代币
-     * 权限：仅限平台管理员
+     * 功能：设置钱包所属用户的类型，用于提货券获取时的用户类型限制，已注册用户账户的同步更新其用户类型
+     * 权限：平台管理员
      */
-    pub fn create_platform_token(
-        ctx: Context<CreatePlatformToken>,
-        decimals: u8,
-        initial_supply: u64,
+    pub fn set_user_type(
+        ctx: Context<SetUserType>,
+        user_type: UserType,
     ) -> Result<()> {
-        let platform_config = &ctx.accounts.platform_config;
+        let platform_config = &mut ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
         
         // 检查管理员权限
         if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
             return Err(SoonShopError::InsufficientAdminPrivilege.into());
         }
 
-        // 铸造初始供应量
-        if initial_supply > 0 {
-            let mint_instruction = MintTo {
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.token_vault.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
+        user_wallet.user_type = user_type.clone();
+        user_wallet.apply_transfer_limit_tier(platform_config.transfer_limit_tiers.limit_for(&user_type));
+        user_wallet.updated_at = Clock::get()?.unix_timestamp;
+
+        // 同步用户账户中的用户类型，并按是否为消费者类型调整平台消费者数量
+        let previous_type = update_optional_account::<UserAccount, _>(&ctx.accounts.user_account, |user_account| {
+            Ok(std::mem::replace(&mut user_account.user_type, user_type.clone()))
+        })?;
+        if let Some(previous_type) = previous_type {
+            let consumer_count = platform_config.statistics.consumer_count;
+            let consumer_count = match (previous_type.is_consumer(), user_type.is_consumer()) {
+                (false, true) => consumer_count.checked_add(1).ok_or(SoonShopError::MathOverflow)?,
+                (true, false) => consumer_count.saturating_sub(1),
+                _ => consumer_count,
             };
-
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                mint_instruction,
-            );
-
-            token::mint_to(cpi_ctx, initial_supply)?;
+            platform_config.update_statistics(StatisticsUpdate {
+                consumer_count: Some(consumer_count),
+                ..Default::default()
+            })?;
         }
 
-        // 发送创建代币事件
-        emit!(PlatformTokenCreated {
-            mint: ctx.accounts.token_mint.key(),
-            vault: ctx.accounts.token_vault.key(),
-            decimals,
-            initial_supply,
+        // 发送用户类型更新事件
+        emit!(UserTypeUpdated {
+            user: user_wallet.owner,
+            user_type,
+            updated_by: ctx.accounts.authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -416,137 +954,141 @@ pub mod soonshop_core {
     }
 
     /**
-     * 铸造代币
+     * 冻结钱包
      * 
-     * 功能：根据需要铸造新的代币，用于奖励分发
-     * 权限：仅限平台管理员
+     * 功能：冻结用户钱包，冻结期间禁止转账、获取和消费提货券额度
+     * 权限：平台管理员
      */
-    pub fn mint_tokens(
-        ctx: Context<MintTokens>,
-        amount: u64,
+    pub fn freeze_wallet(
+        ctx: Context<FreezeWallet>,
+        reason: String,
     ) -> Result<()> {
         let platform_config = &ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
         
         // 检查管理员权限
-        if !platform_config.has_admin_permission(&ctx.accounts.mint_authority.key()) {
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
             return Err(SoonShopError::InsufficientAdminPrivilege.into());
         }
 
-        // 执行铸造
-        let mint_instruction = MintTo {
-            mint: ctx.accounts.token_mint.to_account_info(),
-            to: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
-        };
+        let previous_status = user_wallet.freeze(reason.clone())?;
+
+        // 发送钱包状态变更事件
+        emit!(WalletStatusChanged {
+            owner: user_wallet.owner,
+            wallet: user_wallet.key(),
+            previous_status,
+            new_status: user_wallet.status.clone(),
+            reason,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_wallet.status_updated_at,
+        });
 
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            mint_instruction,
-        );
+        Ok(())
+    }
 
-        token::mint_to(cpi_ctx, amount)?;
+    /**
+     * 暂停钱包
+     * 
+     * 功能：暂停用户钱包，暂停期间禁止转出和消费，仍可接收代币和获取提货券额度
+     * 权限：平台管理员
+     */
+    pub fn suspend_wallet(
+        ctx: Context<SuspendWallet>,
+        reason: String,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
 
-        // 发送铸造事件
-        emit!(TokensMinted {
-            mint: ctx.accounts.token_mint.key(),
-            to: ctx.accounts.recipient_token_account.key(),
-            amount,
-            timestamp: Clock::get()?.unix_timestamp,
+        let previous_status = user_wallet.suspend(reason.clone())?;
+
+        // 发送钱包状态变更事件
+        emit!(WalletStatusChanged {
+            owner: user_wallet.owner,
+            wallet: user_wallet.key(),
+            previous_status,
+            new_status: user_wallet.status.clone(),
+            reason,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_wallet.status_updated_at,
         });
 
         Ok(())
     }
 
     /**
-     * 销毁代币
+     * 解除钱包冻结
      * 
-     * 功能：销毁已使用的代币，控制代币供应量
-     * 权限：代币持有者
+     * 功能：解除用户钱包的冻结或暂停状态，恢复正常使用
+     * 权限：平台管理员
      */
-    pub fn burn_tokens(
-        ctx: Context<BurnTokens>,
-        amount: u64,
+    pub fn unfreeze_wallet(
+        ctx: Context<UnfreezeWallet>,
+        reason: String,
     ) -> Result<()> {
-        // 执行销毁
-        let burn_instruction = Burn {
-            mint: ctx.accounts.token_mint.to_account_info(),
-            from: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.owner.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            burn_instruction,
-        );
-
-        token::burn(cpi_ctx, amount)?;
+        let platform_config = &ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
 
-        // 发送销毁事件
-        emit!(TokensBurned {
-            mint: ctx.accounts.token_mint.key(),
-            from: ctx.accounts.token_account.key(),
-            amount,
-            timestamp: Clock::get()?.unix_timestamp,
+        let previous_status = user_wallet.unfreeze(reason.clone())?;
+
+        // 发送钱包状态变更事件
+        emit!(WalletStatusChanged {
+            owner: user_wallet.owner,
+            wallet: user_wallet.key(),
+            previous_status,
+            new_status: user_wallet.status.clone(),
+            reason,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_wallet.status_updated_at,
         });
 
         Ok(())
     }
 
-    // ================================
-    // 提货券额度功能
-    // ================================
-
     /**
-     * 发行提货券额度
+     * 关闭钱包
      * 
-     * 功能：生产者发布提货券时，分配相应的代币额度
-     * 权限：生产者本人
+     * 功能：钱包所有者关闭代币余额为零、交易记录和提货券额度账户已全部关闭且没有未结消费的钱包并取回租金
+     * 权限：钱包所有者本人
      */
-    pub fn issue_voucher_credits(
-        ctx: Context<IssueVoucherCredits>,
-        voucher_id: String,
-        credit_amount: u64,
-        product_info: ProductInfo,
-        expires_at: Option<i64>,
+    pub fn close_user_wallet(
+        ctx: Context<CloseUserWallet>,
     ) -> Result<()> {
-        let voucher = &mut ctx.accounts.voucher_account;
-        let producer_wallet = &mut ctx.accounts.producer_wallet;
+        let user_wallet = &mut ctx.accounts.user_wallet;
         
         // 检查权限
-        if producer_wallet.owner != ctx.accounts.producer.key() {
+        if user_wallet.owner != ctx.accounts.user.key() {
             return Err(SoonShopError::Unauthorized.into());
         }
+        if ctx.accounts.platform_token_account.key() != user_wallet.platform_token_account {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
 
-        // 创建提货券配置
-        let voucher_config = VoucherConfig {
-            allow_partial_claim: true,
-            allow_transfer: false,
-            require_appointment: false,
-            min_claim_amount: 1,
-            max_claim_amount: credit_amount,
-            claim_fee: 0,
-            cancellation_fee: 0,
-            require_identity_verification: false,
-            allowed_user_types: vec![UserType::Regular],
-            geographic_restrictions: vec![],
-        };
-
-        // 初始化提货券
-        voucher.initialize(
-            voucher_id.clone(),
-            ctx.accounts.producer.key(),
-            product_info,
-            credit_amount,
-            expires_at,
-            voucher_config,
-        )?;
+        // 检查钱包状态和余额，并将钱包标记为已关闭
+        user_wallet.mark_closed(ctx.accounts.platform_token_account.amount)?;
 
-        // 发送发行事件
-        emit!(VoucherCreditsIssued {
-            voucher_id,
-            producer: ctx.accounts.producer.key(),
-            credit_amount,
-            expires_at,
+        // 更新平台统计
+        let platform_config = &mut ctx.accounts.platform_config;
+        let active_users = platform_config.statistics.active_users;
+        platform_config.update_statistics(StatisticsUpdate {
+            active_users: Some(active_users.saturating_sub(1)),
+            ..Default::default()
+        })?;
+
+        // 发送关闭钱包事件
+        emit!(WalletClosed {
+            owner: ctx.accounts.user.key(),
+            wallet: ctx.accounts.user_wallet.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -554,34 +1096,49 @@ pub mod soonshop_core {
     }
 
     /**
-     * 获取提货券额度
+     * 关闭交易记录
      * 
-     * 功能：符合条件的消费者获取提货券对应的代币额度
-     * 权限：消费者本人
+     * 功能：钱包所有者从最新一条开始按序号倒序关闭交易记录并取回租金，关闭钱包前需关闭全部交易记录
+     * 权限：钱包所有者本人
+     * 
+     * remaining_accounts 依次传入序号从大到小、连续的交易记录账户
      */
-    pub fn claim_voucher_credits(
-        ctx: Context<ClaimVoucherCredits>,
-        claim_amount: u64,
+    pub fn close_transaction_records<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseTransactionRecords<'info>>,
     ) -> Result<()> {
-        let voucher = &mut ctx.accounts.voucher_account;
-        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
+        let user_wallet = &mut ctx.accounts.user_wallet;
         
         // 检查权限
-        if consumer_wallet.owner != ctx.accounts.consumer.key() {
+        if user_wallet.owner != ctx.accounts.user.key() {
             return Err(SoonShopError::Unauthorized.into());
         }
+        if ctx.remaining_accounts.is_empty() {
+            return Err(SoonShopError::InvalidParameter.into());
+        }
 
-        // 执行获取
-        voucher.claim_credits(claim_amount)?;
-
-        // 更新消费者钱包
-        consumer_wallet.add_income(claim_amount)?;
+        // 只能关闭当前最新的交易记录，保证剩余记录序号连续、新记录不会与已有记录冲突
+        for account_info in ctx.remaining_accounts {
+            let record = Account::<TransactionRecord>::try_from(account_info)?;
+            if record.user != user_wallet.owner {
+                return Err(SoonShopError::Unauthorized.into());
+            }
+            let sequence = user_wallet.record_count
+                .checked_sub(1)
+                .ok_or(SoonShopError::MathUnderflow)?;
+            if record.sequence != sequence {
+                return Err(SoonShopError::InvalidParameter.into());
+            }
+
+            record.close(ctx.accounts.user.to_account_info())?;
+            user_wallet.record_count = sequence;
+        }
 
-        // 发送获取事件
-        emit!(VoucherCreditsClaimed {
-            voucher_id: voucher.id.clone(),
-            consumer: ctx.accounts.consumer.key(),
-            claim_amount,
+        // 发送交易记录关闭事件
+        emit!(TransactionRecordsClosed {
+            owner: user_wallet.owner,
+            wallet: user_wallet.key(),
+            closed_count: ctx.remaining_accounts.len() as u32,
+            remaining_count: user_wallet.record_count,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -589,376 +1146,3646 @@ pub mod soonshop_core {
     }
 
     /**
-     * 消费提货券额度
+     * 设置日转账限额
      * 
-     * 功能：消费者使用提货券额度购买商品或服务
-     * 权限：消费者本人
+     * 功能：钱包所有者在用户类型默认值或管理员指定上限以内调整自己的日转账限额
+     * 权限：钱包所有者本人
      */
-    pub fn consume_voucher_credits(
-        ctx: Context<ConsumeVoucherCredits>,
-        voucher_id: String,
-        consume_amount: u64,
-        location: String,
-        notes: String,
+    pub fn set_daily_transfer_limit(
+        ctx: Context<SetDailyTransferLimit>,
+        limit: u64,
     ) -> Result<()> {
-        let voucher = &mut ctx.accounts.voucher_account;
-        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
-        let consumption_record = &mut ctx.accounts.consumption_record;
+        let platform_config = &ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
         
         // 检查权限
-        if consumer_wallet.owner != ctx.accounts.consumer.key() {
+        if user_wallet.owner != ctx.accounts.user.key() {
             return Err(SoonShopError::Unauthorized.into());
         }
 
-        // 检查提货券ID匹配
-        if voucher.id != voucher_id {
-            return Err(SoonShopError::VoucherNotFound.into());
+        let tier_limit = platform_config.transfer_limit_tiers.limit_for(&user_wallet.user_type);
+        user_wallet.set_daily_transfer_limit(limit, tier_limit)?;
+
+        // 发送转账限额更新事件
+        emit!(TransferLimitUpdated {
+            owner: user_wallet.owner,
+            daily_transfer_limit: user_wallet.permissions.daily_transfer_limit,
+            transfer_limit_override: user_wallet.transfer_limit_override,
+            updated_by: ctx.accounts.user.key(),
+            timestamp: user_wallet.updated_at,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 覆盖日转账限额
+     * 
+     * 功能：为指定钱包设置高于（或低于）用户类型默认值的日转账限额上限，传入None时恢复默认值
+     * 权限：平台管理员
+     */
+    pub fn override_transfer_limit(
+        ctx: Context<OverrideTransferLimit>,
+        limit: Option<u64>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let user_wallet = &mut ctx.accounts.user_wallet;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
         }
 
-        // 执行消费
-        voucher.consume_credits(consume_amount)?;
+        let tier_limit = platform_config.transfer_limit_tiers.limit_for(&user_wallet.user_type);
+        user_wallet.override_transfer_limit(limit, tier_limit)?;
 
-        // 更新消费者钱包
-        consumer_wallet.add_expense(consume_amount)?;
+        // 发送转账限额更新事件
+        emit!(TransferLimitUpdated {
+            owner: user_wallet.owner,
+            daily_transfer_limit: user_wallet.permissions.daily_transfer_limit,
+            transfer_limit_override: user_wallet.transfer_limit_override,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_wallet.updated_at,
+        });
+
+        Ok(())
+    }
 
-        // 生成消费记录ID
-        let consumption_id = format!("consumption_{}_{}", voucher_id, Clock::get()?.unix_timestamp);
+    // ================================
+    // 用户账户功能
+    // ================================
 
-        // 初始化消费记录
-        consumption_record.initialize(
-            consumption_id.clone(),
-            voucher_id.clone(),
-            ctx.accounts.consumer.key(),
-            ctx.accounts.merchant.key(),
-            consume_amount,
-            1, // 假设数量为1
-            location,
-            notes,
-        )?;
+    /**
+     * 注册用户账户
+     * 
+     * 功能：创建链上用户账户并登记用户类型，注册后需经管理员认证才能被授予角色
+     * 权限：任何用户
+     */
+    pub fn register_user_account(
+        ctx: Context<RegisterUserAccount>,
+        username: String,
+        user_type: UserType,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let user_account = &mut ctx.accounts.user_account;
+
+        user_account.initialize(ctx.accounts.user.key(), user_type.clone(), username.clone())?;
+
+        // 更新平台统计（仅消费者类型计入消费者数量）
+        if user_type.is_consumer() {
+            let consumer_count = platform_config.statistics.consumer_count
+                .checked_add(1)
+                .ok_or(SoonShopError::MathOverflow)?;
+            platform_config.update_statistics(StatisticsUpdate {
+                consumer_count: Some(consumer_count),
+                ..Default::default()
+            })?;
+        }
 
-        // 发送消费事件
-        emit!(VoucherCreditsConsumed {
-            voucher_id,
-            consumer: ctx.accounts.consumer.key(),
-            merchant: ctx.accounts.merchant.key(),
-            consume_amount,
-            consumption_id,
-            timestamp: Clock::get()?.unix_timestamp,
+        // 发送用户账户注册事件
+        emit!(UserAccountRegistered {
+            user: ctx.accounts.user.key(),
+            username,
+            user_type,
+            timestamp: user_account.created_at,
         });
 
         Ok(())
     }
 
     /**
-     * 核销提货券额度
+     * 认证用户账户
      * 
-     * 功能：商家确认商品交付，触发倍增奖励分发
-     * 权限：商家本人
+     * 功能：设置用户账户的认证状态，认证通过时将用户类型同步到用户钱包，撤销认证后该用户的角色校验全部失败
+     * 权限：平台管理员
      */
-    pub fn verify_voucher_consumption(
-        ctx: Context<VerifyVoucherConsumption>,
-        quality_score: u8,
+    pub fn verify_user_account(
+        ctx: Context<VerifyUserAccount>,
+        verified: bool,
     ) -> Result<()> {
-        let consumption_record = &mut ctx.accounts.consumption_record;
-        let _merchant_wallet = &mut ctx.accounts.merchant_wallet;
+        let platform_config = &ctx.accounts.platform_config;
+        let user_account = &mut ctx.accounts.user_account;
         
-        // 检查权限
-        if consumption_record.merchant != ctx.accounts.merchant.key() {
-            return Err(SoonShopError::Unauthorized.into());
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
         }
 
-        // 确认消费
-        consumption_record.confirm(quality_score)?;
+        user_account.set_verified(verified, ctx.accounts.authority.key())?;
+
+        // 认证通过后以用户账户中的用户类型为准，同步到用户钱包并应用对应的日转账限额
+        if verified {
+            let user_type = user_account.user_type.clone();
+            let tier_limit = platform_config.transfer_limit_tiers.limit_for(&user_type);
+            update_optional_account::<UserWallet, _>(&ctx.accounts.user_wallet, |user_wallet| {
+                user_wallet.user_type = user_type;
+                user_wallet.apply_transfer_limit_tier(tier_limit);
+                user_wallet.updated_at = Clock::get()?.unix_timestamp;
+                Ok(())
+            })?;
+        }
 
-        // 发送核销事件
-        emit!(VoucherConsumptionVerified {
-            consumption_id: consumption_record.id.clone(),
-            merchant: ctx.accounts.merchant.key(),
-            quality_score,
-            timestamp: Clock::get()?.unix_timestamp,
+        // 发送用户认证状态更新事件
+        emit!(UserAccountVerified {
+            user: user_account.user_id,
+            verified,
+            verified_by: ctx.accounts.authority.key(),
+            timestamp: user_account.updated_at,
         });
 
         Ok(())
     }
 
     /**
-     * 分发倍增奖励
+     * 授予用户角色
      * 
-     * 功能：根据消费金额和质量评分计算倍增奖励，分发给相关方
-     * 权限：自动触发或管理员调用
+     * 功能：为已认证用户授予生产者、商家或评估员角色
+     * 权限：平台管理员
      */
-    pub fn distribute_multiplier_rewards(
-        ctx: Context<DistributeMultiplierRewards>,
+    pub fn grant_user_role(
+        ctx: Context<UpdateUserRole>,
+        role: UserRole,
     ) -> Result<()> {
-        let consumption_record = &mut ctx.accounts.consumption_record;
-        let producer_wallet = &mut ctx.accounts.producer_wallet;
-        let platform_config = &ctx.accounts.platform_config;
+        let platform_config = &mut ctx.accounts.platform_config;
+        let user_account = &mut ctx.accounts.user_account;
         
-        // 检查消费记录状态
-        if consumption_record.status != ConsumptionStatus::Confirmed {
-            return Err(SoonShopError::InvalidConsumptionStatus.into());
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
         }
 
-        // 获取质量评分
-        let quality_score = consumption_record.quality_score.unwrap_or(5);
-        
-        // 计算奖励
-        let base_reward = consumption_record.amount * platform_config.base_multiplier as u64;
-        let quality_reward = base_reward * quality_score as u64 / 10;
-        let producer_reward = quality_reward * 70 / 100; // 生产者获得70%
-        let platform_reward = quality_reward * 30 / 100; // 平台获得30%
+        user_account.grant_role(&role)?;
 
-        // 更新奖励信息
-        consumption_record.reward_info.base_reward = base_reward;
-        consumption_record.reward_info.quality_reward = quality_reward;
-        consumption_record.reward_info.multiplier_reward = quality_reward;
-        consumption_record.reward_info.total_reward = quality_reward;
-        consumption_record.reward_info.reward_status = RewardStatus::Distributed;
-        consumption_record.reward_info.reward_distributed_at = Some(Clock::get()?.unix_timestamp);
+        // 更新生产者统计
+        if role == UserRole::Producer {
+            let producer_count = platform_config.statistics.producer_count;
+            platform_config.update_statistics(StatisticsUpdate {
+                producer_count: Some(producer_count.checked_add(1).ok_or(SoonShopError::MathOverflow)?),
+                ..Default::default()
+            })?;
+        }
 
-        // 更新生产者钱包
-        producer_wallet.add_reward(producer_reward)?;
+        // 发送用户角色更新事件
+        emit!(UserRoleUpdated {
+            user: user_account.user_id,
+            role,
+            granted: true,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_account.updated_at,
+        });
 
-        // 完成消费记录
-        consumption_record.complete()?;
+        Ok(())
+    }
 
-        // 发送奖励分发事件
-        emit!(MultiplierRewardsDistributed {
-            consumption_id: consumption_record.id.clone(),
-            producer: producer_wallet.owner,
-            producer_reward,
-            platform_reward,
-            quality_score,
-            timestamp: Clock::get()?.unix_timestamp,
+    /**
+     * 撤销用户角色
+     * 
+     * 功能：撤销用户已被授予的角色
+     * 权限：平台管理员
+     */
+    pub fn revoke_user_role(
+        ctx: Context<UpdateUserRole>,
+        role: UserRole,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let user_account = &mut ctx.accounts.user_account;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        user_account.revoke_role(&role)?;
+
+        // 更新生产者统计
+        if role == UserRole::Producer {
+            let producer_count = platform_config.statistics.producer_count;
+            platform_config.update_statistics(StatisticsUpdate {
+                producer_count: Some(producer_count.saturating_sub(1)),
+                ..Default::default()
+            })?;
+        }
+
+        // 发送用户角色更新事件
+        emit!(UserRoleUpdated {
+            user: user_account.user_id,
+            role,
+            granted: false,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: user_account.updated_at,
         });
 
         Ok(())
     }
-}
 
+    // ================================
+    // 代币发行功能
+    // ================================
+
+    /**
+     * 创建平台代币
+     * 
+     * 功能：登记用于提货券额度、奖励分发的平台代币（铸币权限须为程序铸币权限PDA，只能登记一次），需要达到审批阈值的多签提案，初始供应量计入本周期铸造预算
+     * 权限：仅限平台管理员
+     */
+    pub fn create_platform_token(
+        ctx: Context<CreatePlatformToken>,
+        decimals: u8,
+        initial_supply: u64,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 平台代币只能登记一次
+        if platform_config.platform_mint.is_some() {
+            return Err(SoonShopError::PlatformTokenAlreadyCreated.into());
+        }
+        if ctx.accounts.token_mint.decimals != decimals {
+            return Err(SoonShopError::InvalidParameter.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::CreatePlatformToken {
+                mint: ctx.accounts.token_mint.key(),
+                vault: ctx.accounts.token_vault.key(),
+                initial_supply,
+            },
+            platform_config,
+        )?;
+
+        platform_config.platform_mint = Some(ctx.accounts.token_mint.key());
+
+        // 铸造初始供应量（检查最大供应量和本周期铸造预算）
+        if initial_supply > 0 {
+            platform_config.record_mint(initial_supply, ctx.accounts.token_mint.supply)?;
+            mint_with_mint_authority(
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_mint,
+                &ctx.accounts.token_vault,
+                &ctx.accounts.mint_authority,
+                ctx.bumps.mint_authority,
+                initial_supply,
+            )?;
+        }
+
+        // 发送创建代币事件
+        emit!(PlatformTokenCreated {
+            mint: ctx.accounts.token_mint.key(),
+            vault: ctx.accounts.token_vault.key(),
+            decimals,
+            initial_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 铸造代币
+     * 
+     * 功能：根据需要铸造新的代币，用于奖励分发，需要达到审批阈值的多签提案，铸造数量受最大供应量和本周期铸造预算限制
+     * 权限：仅限平台管理员
+     */
+    pub fn mint_tokens(
+        ctx: Context<MintTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let platform_config = &mut ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 执行多签提案
+        ctx.accounts.admin_proposal.execute(
+            &AdminAction::MintTokens {
+                mint: ctx.accounts.token_mint.key(),
+                recipient: ctx.accounts.recipient_token_account.key(),
+                amount,
+            },
+            platform_config,
+        )?;
+
+        // 检查最大供应量和本周期铸造预算
+        platform_config.record_mint(amount, ctx.accounts.token_mint.supply)?;
+
+        // 以铸币权限PDA签名执行铸造
+        mint_with_mint_authority(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.recipient_token_account,
+            &ctx.accounts.mint_authority,
+            ctx.bumps.mint_authority,
+            amount,
+        )?;
+
+        // 发送铸造事件
+        let current_time = Clock::get()?.unix_timestamp;
+        emit!(TokensMinted {
+            mint: ctx.accounts.token_mint.key(),
+            to: ctx.accounts.recipient_token_account.key(),
+            amount,
+            epoch: ctx.accounts.platform_config.mint_epoch.epoch,
+            remaining_epoch_budget: ctx.accounts.platform_config.remaining_mint_budget(current_time),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 查询铸造预算
+     * 
+     * 功能：获取平台代币的剩余可铸造供应量及本周期剩余铸造预算
+     * 权限：任何人
+     */
+    pub fn get_mint_budget(
+        ctx: Context<GetMintBudget>,
+    ) -> Result<MintBudgetInfo> {
+        let platform_config = &ctx.accounts.platform_config;
+        let settings = &platform_config.inflation_settings;
+        let current_supply = ctx.accounts.token_mint.supply;
+        let current_time = Clock::get()?.unix_timestamp;
+        let mint_epoch = platform_config.current_mint_epoch(current_time);
+        let epoch_budget = mint_epoch.budget(settings.base_epoch_budget, platform_config.max_multiplier);
+
+        Ok(MintBudgetInfo {
+            max_supply: settings.max_supply,
+            current_supply,
+            remaining_supply: settings.max_supply.saturating_sub(current_supply),
+            epoch: mint_epoch.epoch,
+            epoch_ends_at: (mint_epoch.epoch + 1) * settings.epoch_duration,
+            epoch_consumption_volume: mint_epoch.consumption_volume,
+            epoch_budget,
+            epoch_minted: mint_epoch.minted,
+            remaining_epoch_budget: epoch_budget.saturating_sub(mint_epoch.minted),
+        })
+    }
+
+    /**
+     * 销毁代币
+     * 
+     * 功能：销毁已使用的代币，控制代币供应量
+     * 权限：代币持有者
+     */
+    pub fn burn_tokens(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        // 执行销毁
+        let burn_instruction = Burn {
+            mint: ctx.accounts.token_mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            burn_instruction,
+        );
+
+        token::burn(cpi_ctx, amount)?;
+
+        // 发送销毁事件
+        emit!(TokensBurned {
+            mint: ctx.accounts.token_mint.key(),
+            from: ctx.accounts.token_account.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 提货券额度功能
+    // ================================
+
+    /**
+     * 发行提货券额度
+     * 
+     * 功能：生产者发布提货券时，将相应数量的平台代币锁定到提货券托管账户
+     * 权限：已认证并被授予生产者角色的生产者本人
+     */
+    pub fn issue_voucher_credits(
+        ctx: Context<IssueVoucherCredits>,
+        voucher_id: String,
+        credit_amount: u64,
+        product_info: ProductInfo,
+        expires_at: Option<i64>,
+        voucher_config: VoucherConfig,
+        claim_restrictions: ClaimRestrictionSettings,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let voucher = &mut ctx.accounts.voucher_account;
+        let producer_wallet = &mut ctx.accounts.producer_wallet;
+        
+        // 检查权限
+        if producer_wallet.owner != ctx.accounts.producer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查生产者认证、角色及信誉
+        let producer_account = &ctx.accounts.producer_account;
+        producer_account.check_role(&UserRole::Producer)?;
+        producer_account.check_reputation(ctx.accounts.platform_config.reputation_settings.min_issue_reputation)?;
+
+        // 检查商品单价与类别参考价格的偏离
+        ctx.accounts.price_data.check_unit_price(
+            product_info.unit_price,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // 校验提货券配置
+        voucher_config.validate(credit_amount)?;
+
+        // 检查生产者代币账户
+        if ctx.accounts.producer_token_account.key() != producer_wallet.platform_token_account {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+
+        // 初始化提货券
+        voucher.initialize(
+            voucher_id.clone(),
+            ctx.accounts.producer.key(),
+            product_info,
+            credit_amount,
+            expires_at,
+            voucher_config,
+        )?;
+        voucher.set_claim_restrictions(claim_restrictions)?;
+        voucher.bump = ctx.bumps.voucher_account;
+
+        // 将额度对应的代币锁定到托管账户
+        let transfer_instruction = Transfer {
+            from: ctx.accounts.producer_token_account.to_account_info(),
+            to: ctx.accounts.voucher_escrow.to_account_info(),
+            authority: ctx.accounts.producer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_instruction,
+        );
+        token::transfer(cpi_ctx, credit_amount)?;
+
+        // 更新平台统计
+        ctx.accounts.platform_config.update_statistics(StatisticsUpdate {
+            vouchers_delta: Some(1),
+            issued_vouchers_delta: Some(1),
+            ..Default::default()
+        })?;
+
+        // 发送发行事件
+        emit!(VoucherCreditsIssued {
+            voucher_id,
+            producer: ctx.accounts.producer.key(),
+            credit_amount,
+            expires_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 获取提货券额度
+     * 
+     * 功能：符合条件的消费者获取提货券额度，额度计入该消费者在此提货券上的可消费余额
+     * 权限：消费者本人
+     */
+    pub fn claim_voucher_credits(
+        ctx: Context<ClaimVoucherCredits>,
+        claim_amount: u64,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let voucher = &mut ctx.accounts.voucher_account;
+        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
+        let voucher_claim = &mut ctx.accounts.voucher_claim;
+        
+        // 检查权限
+        if consumer_wallet.owner != ctx.accounts.consumer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查钱包状态
+        consumer_wallet.check_can_receive()?;
+
+        // 执行获取（按提货券配置和获取限制校验）
+        voucher.claim_credits(
+            claim_amount,
+            &consumer_wallet.user_type,
+            voucher_claim.claimed_amount,
+        )?;
+        if voucher_claim.is_new() {
+            consumer_wallet.open_voucher_claim()?;
+        }
+        voucher_claim.record_claim(voucher.key(), ctx.accounts.consumer.key(), claim_amount)?;
+
+        // 更新消费者钱包
+        consumer_wallet.add_income(claim_amount)?;
+        ctx.accounts.transaction_record.record(consumer_wallet, TransactionEntry {
+            transaction_type: TransactionType::VoucherClaim,
+            amount: claim_amount,
+            token_mint: ctx.accounts.voucher_escrow.mint,
+            counterparty: Some(voucher.producer),
+            reward_type: None,
+            description: String::new(),
+        })?;
+
+        // 更新平台统计
+        ctx.accounts.platform_config.update_statistics(StatisticsUpdate {
+            claimed_vouchers_delta: Some(1),
+            transactions_delta: Some(1),
+            ..Default::default()
+        })?;
+
+        // 发送获取事件
+        emit!(VoucherCreditsClaimed {
+            voucher_id: voucher.id.clone(),
+            consumer: ctx.accounts.consumer.key(),
+            claim_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 转让已获取的提货券额度
+     * 
+     * 功能：持有人将已获取未消费的提货券额度转给其他钱包，便于家庭、机构成员共享提货券，需提货券配置允许转让
+     * 权限：转出方本人
+     */
+    pub fn transfer_claimed_credits(
+        ctx: Context<TransferClaimedCredits>,
+        amount: u64,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let voucher = &ctx.accounts.voucher_account;
+        let sender_wallet = &mut ctx.accounts.sender_wallet;
+        let recipient_wallet = &mut ctx.accounts.recipient_wallet;
+        
+        // 检查权限
+        if sender_wallet.owner != ctx.accounts.sender.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+        if recipient_wallet.owner == sender_wallet.owner {
+            return Err(SoonShopError::InvalidTransferRecipient.into());
+        }
+        if amount == 0 {
+            return Err(SoonShopError::InvalidAmount.into());
+        }
+
+        // 检查双方钱包状态
+        sender_wallet.check_can_send()?;
+        recipient_wallet.check_can_receive()?;
+
+        // 检查提货券是否允许转让
+        voucher.check_transferable()?;
+
+        // 转移持有人余额
+        ctx.accounts.sender_claim.transfer_out(amount)?;
+        if ctx.accounts.recipient_claim.is_new() {
+            recipient_wallet.open_voucher_claim()?;
+        }
+        ctx.accounts.recipient_claim.receive_transfer(voucher.key(), recipient_wallet.owner, amount)?;
+
+        // 更新钱包统计并写入双方交易记录
+        sender_wallet.add_expense(amount)?;
+        recipient_wallet.add_income(amount)?;
+        let token_mint = ctx.accounts.voucher_escrow.mint;
+        ctx.accounts.sender_record.record(sender_wallet, TransactionEntry {
+            transaction_type: TransactionType::TransferSent,
+            amount,
+            token_mint,
+            counterparty: Some(recipient_wallet.owner),
+            reward_type: None,
+            description: voucher.id.clone(),
+        })?;
+        ctx.accounts.recipient_record.record(recipient_wallet, TransactionEntry {
+            transaction_type: TransactionType::TransferReceived,
+            amount,
+            token_mint,
+            counterparty: Some(sender_wallet.owner),
+            reward_type: None,
+            description: voucher.id.clone(),
+        })?;
+
+        // 更新平台统计
+        ctx.accounts.platform_config.update_statistics(StatisticsUpdate {
+            transactions_delta: Some(1),
+            ..Default::default()
+        })?;
+
+        // 发送额度转让事件
+        emit!(VoucherCreditsTransferred {
+            voucher_id: voucher.id.clone(),
+            from: sender_wallet.owner,
+            to: recipient_wallet.owner,
+            amount,
+            sender_balance: ctx.accounts.sender_claim.balance,
+            recipient_balance: ctx.accounts.recipient_claim.balance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 关闭提货券额度账户
+     * 
+     * 功能：持有人关闭余额已用尽的提货券额度账户并取回租金，提货券已过期或已关闭时剩余额度作废
+     * 权限：持有人本人
+     */
+    pub fn close_voucher_claim(
+        ctx: Context<CloseVoucherClaim>,
+    ) -> Result<()> {
+        let voucher_claim = &ctx.accounts.voucher_claim;
+        let holder_wallet = &mut ctx.accounts.holder_wallet;
+
+        // 消费被取消或争议退回时需要将额度退回额度账户，有未结消费时不能关闭
+        if holder_wallet.open_consumptions > 0 {
+            return Err(SoonShopError::WalletHasOpenConsumptions.into());
+        }
+
+        // 仍有余额时，只有提货券已过期或已关闭（额度已作废）才能关闭
+        if voucher_claim.balance > 0 {
+            if let Some(voucher) = load_optional_account::<Voucher>(&ctx.accounts.voucher_account)? {
+                if voucher.status != VoucherStatus::Expired {
+                    return Err(SoonShopError::VoucherClaimNotEmpty.into());
+                }
+            }
+        }
+
+        holder_wallet.close_voucher_claim();
+
+        // 发送额度账户关闭事件
+        emit!(VoucherClaimClosed {
+            voucher: ctx.accounts.voucher_account.key(),
+            holder: ctx.accounts.holder.key(),
+            forfeited_balance: voucher_claim.balance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 消费提货券额度
+     * 
+     * 功能：消费者使用提货券额度购买商品或服务
+     * 权限：消费者本人
+     */
+    pub fn consume_voucher_credits(
+        ctx: Context<ConsumeVoucherCredits>,
+        voucher_id: String,
+        consume_amount: u64,
+        quantity: u64,
+        location: String,
+        notes: String,
+        proof_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let voucher = &mut ctx.accounts.voucher_account;
+        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
+        let voucher_claim = &mut ctx.accounts.voucher_claim;
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        
+        // 检查权限
+        if consumer_wallet.owner != ctx.accounts.consumer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查钱包状态
+        consumer_wallet.check_can_send()?;
+
+        // 检查商家认证及角色
+        ctx.accounts.merchant_account.check_role(&UserRole::Merchant)?;
+
+        // 检查提货券ID匹配
+        if voucher.id != voucher_id {
+            return Err(SoonShopError::VoucherNotFound.into());
+        }
+
+        // 检查消费金额和数量
+        if consume_amount == 0 {
+            return Err(SoonShopError::InvalidAmount.into());
+        }
+        if quantity == 0 {
+            return Err(SoonShopError::InvalidConsumptionQuantity.into());
+        }
+
+        // 以提货券消费序号生成消费记录ID（与消费记录PDA种子一致）
+        let consumption_id = format!("{}_{}", voucher_id, voucher.statistics.consumption_count);
+
+        // 执行消费（托管代币在消费结算后释放给商家）
+        voucher_claim.consume(consume_amount)?;
+        voucher.consume_credits(consume_amount, Clock::get()?.unix_timestamp)?;
+
+        // 更新消费者钱包
+        consumer_wallet.add_expense(consume_amount)?;
+        consumer_wallet.open_consumption()?;
+        ctx.accounts.transaction_record.record(consumer_wallet, TransactionEntry {
+            transaction_type: TransactionType::VoucherConsumption,
+            amount: consume_amount,
+            token_mint: ctx.accounts.voucher_escrow.mint,
+            counterparty: Some(ctx.accounts.merchant.key()),
+            reward_type: None,
+            description: String::new(),
+        })?;
+
+        // 更新平台统计
+        ctx.accounts.platform_config.update_statistics(StatisticsUpdate {
+            consumed_vouchers_delta: Some(1),
+            consumption_amount_delta: Some(consume_amount),
+            transactions_delta: Some(1),
+            ..Default::default()
+        })?;
+
+        // 初始化消费记录
+        consumption_record.initialize(ConsumptionParams {
+            id: consumption_id.clone(),
+            voucher_id: voucher_id.clone(),
+            voucher: ctx.accounts.voucher_account.key(),
+            consumer: ctx.accounts.consumer.key(),
+            merchant: ctx.accounts.merchant.key(),
+            amount: consume_amount,
+            quantity,
+            location,
+            notes,
+        })?;
+
+        // 附带商家（POS终端）签名的交付证明时，校验同一交易中的Ed25519预编译指令
+        if let Some(proof_hash) = proof_hash {
+            let message = ConsumptionProof::signed_message(&consumption_record.key(), &proof_hash);
+            let signature = verify_ed25519_signature(
+                &ctx.accounts.instructions_sysvar,
+                &ctx.accounts.merchant.key(),
+                &message,
+            )?;
+            consumption_record.attach_signature_proof(&proof_hash, &signature)?;
+        }
+
+        // 发送消费事件
+        emit!(VoucherCreditsConsumed {
+            voucher_id,
+            consumer: ctx.accounts.consumer.key(),
+            merchant: ctx.accounts.merchant.key(),
+            consume_amount,
+            quantity,
+            consumption_id,
+            proof_verified: consumption_record.proof.verified,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 核销提货券额度
+     * 
+     * 功能：商家确认商品交付并给出质量评分，评分计入提货券平均质量评分和生产者信誉，消费对应的托管代币在争议期结束后才释放给商家
+     * 权限：已认证并被授予商家角色的商家本人
+     */
+    pub fn verify_voucher_consumption(
+        ctx: Context<VerifyVoucherConsumption>,
+        quality_score: u8,
+        proof_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        let merchant_wallet = &ctx.accounts.merchant_wallet;
+        
+        // 检查权限
+        if consumption_record.merchant != ctx.accounts.merchant.key()
+            || merchant_wallet.owner != ctx.accounts.merchant.key()
+        {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查商家钱包可以接收托管释放的代币
+        merchant_wallet.check_can_receive()?;
+
+        // 检查商家认证及角色
+        ctx.accounts.merchant_account.check_role(&UserRole::Merchant)?;
+
+        // 消费时未附带交付证明的，可在核销时补充商家签名证明
+        if let Some(proof_hash) = proof_hash {
+            let message = ConsumptionProof::signed_message(&consumption_record.key(), &proof_hash);
+            let signature = verify_ed25519_signature(
+                &ctx.accounts.instructions_sysvar,
+                &ctx.accounts.merchant.key(),
+                &message,
+            )?;
+            consumption_record.attach_signature_proof(&proof_hash, &signature)?;
+        }
+
+        let voucher = &mut ctx.accounts.voucher_account;
+
+        // 确认消费
+        let current_time = Clock::get()?.unix_timestamp;
+        consumption_record.confirm(quality_score, current_time)?;
+
+        // 更新提货券平均质量评分和生产者信誉
+        voucher.record_quality_score(quality_score)?;
+        let producer_account = &mut ctx.accounts.producer_account;
+        producer_account.record_quality_score(quality_score, current_time);
+
+        // 发送核销事件
+        emit!(VoucherConsumptionVerified {
+            consumption_id: consumption_record.id.clone(),
+            merchant: ctx.accounts.merchant.key(),
+            quality_score,
+            proof_verified: consumption_record.proof.verified,
+            producer_reputation: producer_account.reputation_score,
+            dispute_deadline: consumption_record.dispute_deadline(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 结算超时未确认的消费
+     * 
+     * 功能：商家超过确认时限仍未核销的消费，按平台配置自动确认（按默认质量评分计入提货券平均质量评分和生产者信誉）或自动取消（退回提货券额度）；已确认的消费在争议期结束后释放托管代币给商家
+     * 权限：任何人（由后台定时任务调用）
+     */
+    pub fn settle_expired_consumption(ctx: Context<SettleExpiredConsumption>) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let platform_config = &mut ctx.accounts.platform_config;
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        let voucher = &mut ctx.accounts.voucher_account;
+        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
+        let voucher_claim = &mut ctx.accounts.voucher_claim;
+
+        // 检查账户匹配
+        if consumer_wallet.owner != consumption_record.consumer {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查代币账户
+        if ctx.accounts.merchant_token_account.owner != consumption_record.merchant
+            || ctx.accounts.merchant_token_account.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+        if ctx.accounts.platform_treasury.owner != ctx.accounts.platform_authority.key()
+            || ctx.accounts.platform_treasury.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // 已确认的消费：争议期结束后释放托管代币给商家，平台手续费转入平台金库
+        if consumption_record.status == ConsumptionStatus::Confirmed {
+            let consumption_fee = release_consumption_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.voucher_escrow,
+                voucher,
+                consumption_record,
+                &ctx.accounts.merchant_token_account,
+                &ctx.accounts.platform_treasury,
+                platform_config,
+            )?;
+            consumer_wallet.settle_consumption();
+
+            emit!(ConsumptionEscrowReleased {
+                consumption_id: consumption_record.id.clone(),
+                merchant_amount: consumption_record.amount - consumption_fee,
+                consumption_fee,
+                released_by: ctx.accounts.cranker.key(),
+                timestamp: current_time,
+            });
+
+            return Ok(());
+        }
+
+        let action = platform_config.auto_settlement_action.clone();
+        consumption_record.auto_settle(&action, current_time)?;
+
+        match action {
+            // 自动确认时与商家核销一致地更新提货券平均质量评分和生产者信誉，托管代币在争议期结束后释放
+            AutoSettlementAction::Confirm => {
+                let quality_score = consumption_record.quality_score
+                    .unwrap_or(constants::DEFAULT_AUTO_SETTLEMENT_QUALITY_SCORE);
+                voucher.record_quality_score(quality_score)?;
+                ctx.accounts.producer_account.record_quality_score(quality_score, current_time);
+            }
+            // 自动取消时退回额度
+            AutoSettlementAction::Cancel => {
+                voucher.refund_consumption(consumption_record.amount, current_time)?;
+                voucher_claim.refund(consumption_record.amount)?;
+                consumer_wallet.add_income(consumption_record.amount)?;
+                consumer_wallet.settle_consumption();
+            }
+        }
+
+        // 发送自动结算事件
+        emit!(ConsumptionAutoSettled {
+            consumption_id: consumption_record.id.clone(),
+            action,
+            settled_by: ctx.accounts.cranker.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 发起消费争议
+     * 
+     * 功能：消费者在争议截止时间前（待确认的消费自消费时起、已确认的消费自确认时起的确认时限内）对消费记录提出争议，争议期间暂停核销、托管代币释放和奖励分发
+     * 权限：消费者本人
+     */
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        reason: String,
+    ) -> Result<()> {
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        
+        // 检查权限
+        if consumption_record.consumer != ctx.accounts.consumer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        consumption_record.open_dispute(reason.clone(), Clock::get()?.unix_timestamp)?;
+
+        // 发送争议发起事件
+        emit!(ConsumptionDisputeOpened {
+            consumption_id: consumption_record.id.clone(),
+            consumer: ctx.accounts.consumer.key(),
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 处理消费争议
+     * 
+     * 功能：仲裁消费争议，退回额度至提货券或维持原消费，退回额度时扣减商家信誉
+     * 权限：管理员；争议超过处理时限后消费者可自行退回额度
+     */
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        outcome: DisputeOutcome,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        let voucher = &mut ctx.accounts.voucher_account;
+        let consumer_wallet = &mut ctx.accounts.consumer_wallet;
+        let voucher_claim = &mut ctx.accounts.voucher_claim;
+        let authority = ctx.accounts.authority.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // 检查权限
+        let is_admin = platform_config.has_admin_permission(&authority);
+        let consumer_timeout_refund = authority == consumption_record.consumer
+            && outcome == DisputeOutcome::RefundToVoucher
+            && consumption_record.dispute_timed_out(current_time);
+        if !is_admin && !consumer_timeout_refund {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查账户匹配
+        if consumer_wallet.owner != consumption_record.consumer {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        consumption_record.resolve_dispute(authority, outcome.clone(), current_time)?;
+
+        // 退回额度并扣减商家信誉
+        if outcome == DisputeOutcome::RefundToVoucher {
+            voucher.refund_consumption(consumption_record.amount, current_time)?;
+            voucher_claim.refund(consumption_record.amount)?;
+            consumer_wallet.add_income(consumption_record.amount)?;
+            consumer_wallet.settle_consumption();
+            ctx.accounts.merchant_account.record_dispute_lost()?;
+        }
+
+        // 发送争议处理事件
+        emit!(ConsumptionDisputeResolved {
+            consumption_id: consumption_record.id.clone(),
+            arbiter: authority,
+            outcome,
+            merchant_reputation: ctx.accounts.merchant_account.reputation_score,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 分发倍增奖励
+     * 
+     * 功能：争议期结束后，根据消费金额、质量评分和生产者信誉计算倍增奖励，从奖励池向生产者、职工、上游供应商和平台金库转出代币，托管代币尚未释放的一并释放给商家
+     * 权限：自动触发或管理员调用
+     * 
+     * remaining_accounts 依次传入：按职工名册顺序排列的职工代币账户，
+     * 以及每一层上游的（上游提货券账户, 上游生产者代币账户）
+     */
+    pub fn distribute_multiplier_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeMultiplierRewards<'info>>,
+    ) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let consumption_record = &mut ctx.accounts.consumption_record;
+        let voucher = &mut ctx.accounts.voucher_account;
+        let producer_wallet = &mut ctx.accounts.producer_wallet;
+        let platform_config = &mut ctx.accounts.platform_config;
+        let reward_pool = &ctx.accounts.reward_pool;
+        
+        // 检查消费记录状态
+        if consumption_record.status == ConsumptionStatus::Disputed {
+            return Err(SoonShopError::ConsumptionDisputed.into());
+        }
+        if consumption_record.status != ConsumptionStatus::Confirmed {
+            return Err(SoonShopError::InvalidConsumptionStatus.into());
+        }
+
+        // 检查提货券与生产者匹配
+        if producer_wallet.owner != voucher.producer {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查代币账户
+        if ctx.accounts.producer_token_account.key() != producer_wallet.platform_token_account {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+        if reward_pool.key() != platform_config.reward_pool {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+        if reward_pool.owner != ctx.accounts.reward_pool_authority.key() {
+            return Err(SoonShopError::InsufficientTokenAuthority.into());
+        }
+        if ctx.accounts.platform_treasury.owner != ctx.accounts.platform_authority.key()
+            || ctx.accounts.platform_treasury.mint != reward_pool.mint
+        {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+        if ctx.accounts.consumer_wallet.owner != consumption_record.consumer {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+        if ctx.accounts.merchant_token_account.owner != consumption_record.merchant
+            || ctx.accounts.merchant_token_account.mint != ctx.accounts.voucher_escrow.mint
+            || ctx.accounts.platform_treasury.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::InvalidTokenAccount.into());
+        }
+
+        // 托管代币尚未释放的，争议期结束后释放给商家，平台手续费转入平台金库
+        let current_time = Clock::get()?.unix_timestamp;
+        if !consumption_record.escrow_released() {
+            let consumption_fee = release_consumption_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.voucher_escrow,
+                voucher,
+                consumption_record,
+                &ctx.accounts.merchant_token_account,
+                &ctx.accounts.platform_treasury,
+                platform_config,
+            )?;
+            ctx.accounts.consumer_wallet.settle_consumption();
+
+            emit!(ConsumptionEscrowReleased {
+                consumption_id: consumption_record.id.clone(),
+                merchant_amount: consumption_record.amount - consumption_fee,
+                consumption_fee,
+                released_by: ctx.accounts.payer.key(),
+                timestamp: current_time,
+            });
+        }
+
+        // 获取质量评分
+        let quality_score = consumption_record.quality_score.unwrap_or(5);
+
+        // 确定倍增系数：管理员覆盖优先，其次为有效期内已批准的企业评估结果
+        let override_multiplier = load_optional_account::<ProducerMultiplier>(&ctx.accounts.multiplier_override)?
+            .and_then(|multiplier_override| multiplier_override.effective_multiplier(current_time));
+        let evaluated_multiplier = load_optional_account::<EnterpriseEvaluation>(&ctx.accounts.enterprise_evaluation)?
+            .and_then(|evaluation| evaluation.effective_multiplier(current_time));
+        let multiplier = platform_config.resolve_multiplier(override_multiplier, evaluated_multiplier);
+        
+        // 计算奖励，低信誉生产者按惩罚比例发放
+        let base_reward = consumption_record.amount
+            .checked_mul(multiplier as u64)
+            .ok_or(SoonShopError::MathOverflow)?;
+        let base_reward = platform_config.reputation_settings
+            .apply_penalty(base_reward, ctx.accounts.producer_account.reputation_score)?;
+        let quality_reward = base_reward
+            .checked_mul(quality_score as u64)
+            .ok_or(SoonShopError::MathOverflow)?
+            / 10;
+        let platform_reward = quality_reward
+            .checked_mul(platform_config.platform_reward_ratio as u64)
+            .ok_or(SoonShopError::MathOverflow)?
+            / constants::RATIO_BASE as u64;
+        let enterprise_reward = quality_reward - platform_reward;
+
+        // 按职工名册计算职工分红
+        let worker_roster = load_optional_account::<WorkerRoster>(&ctx.accounts.worker_roster)?;
+        let (producer_reward, worker_shares) = split_enterprise_reward(
+            enterprise_reward,
+            platform_config.worker_reward_ratio,
+            worker_roster.as_ref(),
+        )?;
+        let worker_reward: u64 = worker_shares.iter().sum();
+
+        // 沿上游提货券链计算链式倍增奖励
+        let first_level_reward = quality_reward
+            .checked_mul(constants::CHAIN_REWARD_RATIO as u64)
+            .ok_or(SoonShopError::MathOverflow)?
+            / constants::RATIO_BASE as u64;
+        let chain_accounts = ctx.remaining_accounts
+            .get(worker_shares.len()..)
+            .ok_or(SoonShopError::InvalidTokenAccount)?;
+        let upstream_rewards = collect_upstream_rewards(
+            voucher,
+            chain_accounts,
+            reward_pool.mint,
+            first_level_reward,
+        )?;
+        let upstream_reward: u64 = upstream_rewards.iter().map(|reward| reward.amount).sum();
+        let total_reward = quality_reward
+            .checked_add(upstream_reward)
+            .ok_or(SoonShopError::MathOverflow)?;
+
+        // 检查奖励池余额
+        if reward_pool.amount < total_reward {
+            return Err(SoonShopError::InsufficientRewardBalance.into());
+        }
+
+        // 从奖励池转出生产者奖励和平台奖励
+        let pool_bump = [ctx.bumps.reward_pool_authority];
+        let pool_seeds: &[&[u8]] = &[constants::REWARD_POOL_SEED, &pool_bump];
+        transfer_from_reward_pool(
+            &ctx.accounts.token_program,
+            reward_pool,
+            &ctx.accounts.reward_pool_authority,
+            &ctx.accounts.producer_token_account,
+            pool_seeds,
+            producer_reward,
+        )?;
+        transfer_from_reward_pool(
+            &ctx.accounts.token_program,
+            reward_pool,
+            &ctx.accounts.reward_pool_authority,
+            &ctx.accounts.platform_treasury,
+            pool_seeds,
+            platform_reward,
+        )?;
+
+        // 向职工分发分红
+        let mut worker_recipients = Vec::with_capacity(worker_shares.len());
+        if let Some(roster) = &worker_roster {
+            if ctx.remaining_accounts.len() < worker_shares.len() {
+                return Err(SoonShopError::InvalidTokenAccount.into());
+            }
+
+            for ((share, amount), account_info) in roster.workers.iter()
+                .zip(worker_shares.iter())
+                .zip(ctx.remaining_accounts.iter())
+            {
+                let worker_token_account = Account::<TokenAccount>::try_from(account_info)?;
+                if worker_token_account.owner != share.worker
+                    || worker_token_account.mint != reward_pool.mint
+                {
+                    return Err(SoonShopError::InvalidTokenAccount.into());
+                }
+
+                transfer_from_reward_pool(
+                    &ctx.accounts.token_program,
+                    reward_pool,
+                    &ctx.accounts.reward_pool_authority,
+                    &worker_token_account,
+                    pool_seeds,
+                    *amount,
+                )?;
+
+                worker_recipients.push(RewardRecipient {
+                    recipient: share.worker,
+                    amount: *amount,
+                    reward_type: "worker".to_string(),
+                    status: RewardStatus::Distributed,
+                });
+            }
+        }
+
+        // 向上游供应商逐层分发链式倍增奖励
+        let mut upstream_recipients = Vec::with_capacity(upstream_rewards.len());
+        for (index, upstream) in upstream_rewards.iter().enumerate() {
+            transfer_from_reward_pool(
+                &ctx.accounts.token_program,
+                reward_pool,
+                &ctx.accounts.reward_pool_authority,
+                &upstream.token_account,
+                pool_seeds,
+                upstream.amount,
+            )?;
+
+            upstream_recipients.push(RewardRecipient {
+                recipient: upstream.producer,
+                amount: upstream.amount,
+                reward_type: format!("upstream_level_{}", index + 1),
+                status: RewardStatus::Distributed,
+            });
+        }
+
+        // 更新奖励信息
+        consumption_record.reward_info.base_reward = base_reward;
+        consumption_record.reward_info.quality_reward = quality_reward;
+        consumption_record.reward_info.multiplier_reward = quality_reward;
+        consumption_record.reward_info.total_reward = total_reward;
+        consumption_record.reward_info.reward_status = RewardStatus::Distributed;
+        consumption_record.reward_info.reward_distributed_at = Some(Clock::get()?.unix_timestamp);
+        consumption_record.reward_info.reward_recipients = vec![
+            RewardRecipient {
+                recipient: producer_wallet.owner,
+                amount: producer_reward,
+                reward_type: "producer".to_string(),
+                status: RewardStatus::Distributed,
+            },
+            RewardRecipient {
+                recipient: ctx.accounts.platform_treasury.key(),
+                amount: platform_reward,
+                reward_type: "platform".to_string(),
+                status: RewardStatus::Distributed,
+            },
+        ];
+        consumption_record.reward_info.reward_recipients.extend(worker_recipients);
+        consumption_record.reward_info.reward_recipients.extend(upstream_recipients);
+
+        // 更新生产者钱包
+        producer_wallet.add_reward(producer_reward)?;
+        ctx.accounts.transaction_record.record(producer_wallet, TransactionEntry {
+            transaction_type: TransactionType::MultiplierReward,
+            amount: producer_reward,
+            token_mint: ctx.accounts.producer_token_account.mint,
+            counterparty: Some(consumption_record.consumer),
+            reward_type: Some(RewardType::MultiplierReward),
+            description: String::new(),
+        })?;
+
+        // 完成消费记录
+        consumption_record.complete()?;
+
+        // 更新平台统计
+        platform_config.update_statistics(StatisticsUpdate {
+            rewards_distributed_delta: Some(total_reward),
+            platform_revenue_delta: Some(platform_reward),
+            transactions_delta: Some(1),
+            ..Default::default()
+        })?;
+
+        // 发送奖励分发事件
+        emit!(MultiplierRewardsDistributed {
+            consumption_id: consumption_record.id.clone(),
+            producer: producer_wallet.owner,
+            producer_reward,
+            worker_reward,
+            upstream_reward,
+            platform_reward,
+            quality_score,
+            multiplier,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 职工分红功能
+    // ================================
+
+    /**
+     * 创建职工名册
+     * 
+     * 功能：生产者登记职工及其分红权重，用于倍增奖励中的职工分红
+     * 权限：生产者本人
+     */
+    pub fn initialize_worker_roster(
+        ctx: Context<InitializeWorkerRoster>,
+        workers: Vec<WorkerShare>,
+    ) -> Result<()> {
+        let worker_roster = &mut ctx.accounts.worker_roster;
+        worker_roster.initialize(ctx.accounts.producer.key(), workers)?;
+
+        // 发送职工名册更新事件
+        emit!(WorkerRosterUpdated {
+            producer: ctx.accounts.producer.key(),
+            worker_count: worker_roster.workers.len() as u8,
+            total_weight: worker_roster.total_weight,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 更新职工名册
+     * 
+     * 功能：生产者调整职工列表及分红权重
+     * 权限：生产者本人
+     */
+    pub fn update_worker_roster(
+        ctx: Context<UpdateWorkerRoster>,
+        workers: Vec<WorkerShare>,
+    ) -> Result<()> {
+        let worker_roster = &mut ctx.accounts.worker_roster;
+        worker_roster.set_workers(workers)?;
+
+        // 发送职工名册更新事件
+        emit!(WorkerRosterUpdated {
+            producer: ctx.accounts.producer.key(),
+            worker_count: worker_roster.workers.len() as u8,
+            total_weight: worker_roster.total_weight,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 提货券生命周期功能
+    // ================================
+
+    /**
+     * 暂停提货券
+     * 
+     * 功能：暂停提货券的获取
+     * 权限：生产者本人或管理员
+     */
+    pub fn pause_voucher(ctx: Context<PauseVoucher>) -> Result<()> {
+        let voucher = &mut ctx.accounts.voucher_account;
+        let authority = ctx.accounts.authority.key();
+        
+        // 检查权限
+        if voucher.producer != authority && !ctx.accounts.platform_config.has_admin_permission(&authority) {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        voucher.pause()?;
+
+        // 发送暂停事件
+        emit!(VoucherPaused {
+            voucher_id: voucher.id.clone(),
+            paused_by: authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 恢复提货券
+     * 
+     * 功能：恢复已暂停提货券的获取
+     * 权限：生产者本人或管理员
+     */
+    pub fn resume_voucher(ctx: Context<ResumeVoucher>) -> Result<()> {
+        let voucher = &mut ctx.accounts.voucher_account;
+        let authority = ctx.accounts.authority.key();
+        
+        // 检查权限
+        if voucher.producer != authority && !ctx.accounts.platform_config.has_admin_permission(&authority) {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        voucher.resume()?;
+
+        // 发送恢复事件
+        emit!(VoucherResumed {
+            voucher_id: voucher.id.clone(),
+            resumed_by: authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 取消提货券
+     * 
+     * 功能：取消提货券并将未获取额度对应的托管代币退回生产者，已获取的额度仍可继续消费；生产者主动取消时向平台金库支付取消费用
+     * 权限：生产者本人或管理员
+     */
+    pub fn cancel_voucher(ctx: Context<CancelVoucher>) -> Result<()> {
+        // 检查平台是否处于紧急暂停状态
+        ctx.accounts.platform_config.check_not_paused()?;
+
+        let voucher = &mut ctx.accounts.voucher_account;
+        let authority = ctx.accounts.authority.key();
+        let is_producer = voucher.producer == authority;
+        
+        // 检查权限
+        if !is_producer && !ctx.accounts.platform_config.has_admin_permission(&authority) {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        // 检查生产者代币账户
+        let producer_token_account = &ctx.accounts.producer_token_account;
+        if producer_token_account.owner != voucher.producer
+            || producer_token_account.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::AccountOwnerMismatch.into());
+        }
+
+        // 退回未获取额度对应的托管代币
+        let refunded_credits = voucher.cancel()?;
+        transfer_from_voucher_escrow(
+            &ctx.accounts.token_program,
+            &ctx.accounts.voucher_escrow,
+            voucher,
+            producer_token_account,
+            refunded_credits,
+        )?;
+
+        // 生产者主动取消时收取取消费用
+        let cancellation_fee = if is_producer { voucher.config.cancellation_fee } else { 0 };
+        if cancellation_fee > 0 {
+            let platform_treasury = &ctx.accounts.platform_treasury;
+            if platform_treasury.owner != ctx.accounts.platform_authority.key()
+                || platform_treasury.mint != producer_token_account.mint
+            {
+                return Err(SoonShopError::InvalidTokenAccount.into());
+            }
+
+            let transfer_instruction = Transfer {
+                from: producer_token_account.to_account_info(),
+                to: platform_treasury.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_instruction,
+            );
+            token::transfer(cpi_ctx, cancellation_fee)?;
+        }
+
+        // 发送取消事件
+        emit!(VoucherCancelled {
+            voucher_id: voucher.id.clone(),
+            cancelled_by: authority,
+            refunded_credits,
+            cancellation_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 过期提货券
+     * 
+     * 功能：将超过过期时间的提货券标记为已过期，并将未获取额度对应的托管代币退回生产者
+     * 权限：任何人（由后台定时任务调用）
+     */
+    pub fn expire_voucher(ctx: Context<ExpireVoucher>) -> Result<()> {
+        let voucher = &mut ctx.accounts.voucher_account;
+
+        // 检查生产者代币账户
+        let producer_token_account = &ctx.accounts.producer_token_account;
+        if producer_token_account.owner != voucher.producer
+            || producer_token_account.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::AccountOwnerMismatch.into());
+        }
+
+        // 退回未获取额度对应的托管代币
+        let refunded_credits = voucher.expire()?;
+        transfer_from_voucher_escrow(
+            &ctx.accounts.token_program,
+            &ctx.accounts.voucher_escrow,
+            voucher,
+            producer_token_account,
+            refunded_credits,
+        )?;
+
+        // 发送过期事件
+        emit!(VoucherExpired {
+            voucher_id: voucher.id.clone(),
+            refunded_credits,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 关闭提货券
+     * 
+     * 功能：提货券完全结算后关闭提货券及其托管账户，剩余代币和租金退回生产者
+     * 权限：生产者本人
+     */
+    pub fn close_voucher(ctx: Context<CloseVoucher>) -> Result<()> {
+        let voucher = &ctx.accounts.voucher_account;
+        
+        // 检查权限
+        if voucher.producer != ctx.accounts.producer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        voucher.check_closable()?;
+
+        // 检查生产者代币账户
+        if ctx.accounts.producer_token_account.owner != voucher.producer
+            || ctx.accounts.producer_token_account.mint != ctx.accounts.voucher_escrow.mint
+        {
+            return Err(SoonShopError::AccountOwnerMismatch.into());
+        }
+
+        // 托管账户剩余代币（已过期作废的额度）退回生产者后关闭托管账户
+        let voucher_escrow = &ctx.accounts.voucher_escrow;
+        transfer_from_voucher_escrow(
+            &ctx.accounts.token_program,
+            voucher_escrow,
+            voucher,
+            &ctx.accounts.producer_token_account,
+            voucher_escrow.amount,
+        )?;
+
+        let bump = [voucher.bump];
+        let voucher_seeds: &[&[u8]] = &[
+            constants::VOUCHER_SEED,
+            voucher.producer.as_ref(),
+            voucher.id.as_bytes(),
+            &bump,
+        ];
+        let signer = &[voucher_seeds];
+        let close_instruction = CloseAccount {
+            account: voucher_escrow.to_account_info(),
+            destination: ctx.accounts.producer.to_account_info(),
+            authority: voucher.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            close_instruction,
+            signer,
+        );
+        token::close_account(cpi_ctx)?;
+
+        // 发送关闭事件
+        emit!(VoucherClosed {
+            voucher_id: voucher.id.clone(),
+            producer: voucher.producer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 链式倍增功能
+    // ================================
+
+    /**
+     * 关联上游提货券
+     * 
+     * 功能：生产者声明提货券所用原材料对应的上游提货券（每个提货券只能关联一个上游，关联后不可更改），消费时奖励沿上游链逐层传递
+     * 权限：生产者本人
+     */
+    pub fn link_upstream_voucher(
+        ctx: Context<LinkUpstreamVoucher>,
+    ) -> Result<()> {
+        let voucher = &mut ctx.accounts.voucher_account;
+        let upstream_voucher = &ctx.accounts.upstream_voucher;
+        
+        // 检查权限
+        if voucher.producer != ctx.accounts.producer.key() {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        voucher.link_upstream(upstream_voucher.key(), &upstream_voucher.producer, current_time)?;
+
+        // 发送上游关联事件
+        emit!(UpstreamVoucherLinked {
+            voucher_id: voucher.id.clone(),
+            producer: voucher.producer,
+            upstream_voucher: upstream_voucher.key(),
+            upstream_producer: upstream_voucher.producer,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 企业评估功能
+    // ================================
+
+    /**
+     * 登记评估员
+     * 
+     * 功能：登记有资格提交企业评估的评估员
+     * 权限：仅限管理员
+     */
+    pub fn register_evaluator(
+        ctx: Context<RegisterEvaluator>,
+        evaluator: Pubkey,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        // 检查评估员数量上限
+        let evaluator_count = platform_config.statistics.evaluator_count;
+        if evaluator_count >= constants::MAX_EVALUATORS as u64 {
+            return Err(SoonShopError::InsufficientEvaluatorPermission.into());
+        }
+
+        ctx.accounts.evaluator_account.initialize(evaluator, ctx.accounts.authority.key())?;
+        platform_config.update_statistics(StatisticsUpdate {
+            evaluator_count: Some(evaluator_count.checked_add(1).ok_or(SoonShopError::MathOverflow)?),
+            ..Default::default()
+        })?;
+
+        // 发送评估员登记事件
+        emit!(EvaluatorRegistered {
+            evaluator,
+            registered_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 移除评估员
+     * 
+     * 功能：注销评估员资格并回收账户租金
+     * 权限：仅限管理员
+     */
+    pub fn remove_evaluator(
+        ctx: Context<RemoveEvaluator>,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let evaluator_count = platform_config.statistics.evaluator_count;
+        platform_config.update_statistics(StatisticsUpdate {
+            evaluator_count: Some(evaluator_count.saturating_sub(1)),
+            ..Default::default()
+        })?;
+
+        // 发送评估员移除事件
+        emit!(EvaluatorRemoved {
+            evaluator: ctx.accounts.evaluator_account.evaluator,
+            removed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 提交企业评估
+     * 
+     * 功能：评估员对生产企业进行多维度评分，提交后等待管理员审批
+     * 权限：已登记且已认证并被授予评估员角色的评估员
+     */
+    pub fn submit_evaluation(
+        ctx: Context<SubmitEvaluation>,
+        evaluation_period: String,
+        scores: EvaluationScores,
+        comments: String,
+    ) -> Result<()> {
+        let evaluator_account = &mut ctx.accounts.evaluator_account;
+        let evaluation = &mut ctx.accounts.evaluation;
+        
+        // 检查评估员资格
+        if !evaluator_account.is_active {
+            return Err(SoonShopError::InsufficientEvaluatorPermission.into());
+        }
+
+        // 检查评估员与被评估企业的认证及角色
+        ctx.accounts.evaluator_user_account.check_role(&UserRole::Evaluator)?;
+        ctx.accounts.enterprise_account.check_role(&UserRole::Producer)?;
+
+        evaluation.submit(
+            ctx.accounts.enterprise.key(),
+            ctx.accounts.evaluator.key(),
+            evaluation_period.clone(),
+            scores,
+            comments,
+        )?;
+
+        evaluator_account.evaluation_count = evaluator_account.evaluation_count
+            .checked_add(1)
+            .ok_or(SoonShopError::MathOverflow)?;
+        evaluator_account.updated_at = Clock::get()?.unix_timestamp;
+
+        // 发送评估提交事件
+        emit!(EvaluationSubmitted {
+            enterprise: ctx.accounts.enterprise.key(),
+            evaluator: ctx.accounts.evaluator.key(),
+            evaluation_period,
+            overall_score: evaluation.overall_score,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 审批企业评估
+     * 
+     * 功能：批准或驳回待审批的企业评估，批准后按综合评分确定企业倍增系数
+     * 权限：仅限管理员
+     */
+    pub fn approve_evaluation(
+        ctx: Context<ApproveEvaluation>,
+        approved: bool,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let evaluation = &mut ctx.accounts.evaluation;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        if approved {
+            let multiplier = platform_config.multiplier_for_score(evaluation.overall_score);
+            evaluation.approve(ctx.accounts.authority.key(), multiplier)?;
+        } else {
+            evaluation.reject()?;
+        }
+
+        // 发送评估审批事件
+        emit!(EvaluationReviewed {
+            enterprise: evaluation.enterprise,
+            approver: ctx.accounts.authority.key(),
+            approved,
+            overall_score: evaluation.overall_score,
+            multiplier: evaluation.multiplier_impact,
+            valid_until: evaluation.valid_until,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 价格监控功能
+    // ================================
+
+    /**
+     * 初始化商品类别价格数据
+     * 
+     * 功能：为商品类别创建参考价格账户并指定授权喂价方
+     * 权限：仅限管理员
+     */
+    pub fn initialize_price_data(
+        ctx: Context<InitializePriceData>,
+        category: ProductCategory,
+        feeders: Vec<Pubkey>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        ctx.accounts.price_data.initialize(category.clone(), feeders)?;
+
+        // 发送价格数据初始化事件
+        emit!(PriceDataInitialized {
+            category,
+            price_data: ctx.accounts.price_data.key(),
+            feeder_count: ctx.accounts.price_data.feeders.len() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 设置授权喂价方
+     * 
+     * 功能：更新商品类别价格数据的授权喂价方列表
+     * 权限：仅限管理员
+     */
+    pub fn set_price_feeders(
+        ctx: Context<SetPriceFeeders>,
+        feeders: Vec<Pubkey>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        ctx.accounts.price_data.set_feeders(feeders)?;
+
+        // 发送喂价方更新事件
+        emit!(PriceFeedersUpdated {
+            category: ctx.accounts.price_data.category.clone(),
+            feeder_count: ctx.accounts.price_data.feeders.len() as u8,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 更新参考价格
+     * 
+     * 功能：授权喂价方提交商品类别的最新参考价格，异常波动的喂价将被拒绝
+     * 权限：授权喂价方
+     */
+    pub fn update_price(
+        ctx: Context<UpdatePrice>,
+        price: u64,
+        confidence: u32,
+    ) -> Result<()> {
+        let price_data = &mut ctx.accounts.price_data;
+        
+        // 检查喂价方权限
+        if !price_data.is_feeder(&ctx.accounts.feeder.key()) {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+
+        price_data.update_price(ctx.accounts.feeder.key(), price, confidence)?;
+
+        // 发送价格更新事件
+        emit!(PriceUpdated {
+            category: price_data.category.clone(),
+            price,
+            confidence,
+            feeder: ctx.accounts.feeder.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 强制更新参考价格
+     * 
+     * 功能：跳过波动校验直接设置参考价格并重置价格历史，用于真实价格剧烈变动后喂价持续被拒绝的情况
+     * 权限：仅限管理员
+     */
+    pub fn force_update_price(
+        ctx: Context<ForceUpdatePrice>,
+        price: u64,
+        confidence: u32,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let price_data = &mut ctx.accounts.price_data;
+        
+        // 检查管理员权限
+        if !platform_config.has_admin_permission(&ctx.accounts.authority.key()) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let previous_price = price_data.current_price;
+        price_data.force_update_price(ctx.accounts.authority.key(), price, confidence)?;
+
+        // 发送价格强制更新事件
+        emit!(PriceForceUpdated {
+            category: price_data.category.clone(),
+            previous_price,
+            price,
+            confidence,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // ================================
+    // 账户迁移功能
+    // ================================
+
+    /**
+     * 迁移平台配置
+     * 
+     * 功能：将旧布局的平台配置扩容到当前布局，保留原有字段并为新增字段填入默认值
+     * 权限：仅限管理员
+     */
+    pub fn migrate_platform_config(
+        ctx: Context<MigratePlatformConfig>,
+    ) -> Result<()> {
+        let account = &ctx.accounts.platform_config;
+        let authority = ctx.accounts.authority.key();
+        let legacy: PlatformConfigV1 =
+            load_legacy_account::<PlatformConfig, _>(account, PlatformConfigV1::SPACE, PlatformConfig::SPACE)?;
+        
+        // 检查管理员权限
+        if legacy.super_admin != authority && !legacy.admins.contains(&authority) {
+            return Err(SoonShopError::InsufficientAdminPrivilege.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let migrated = legacy.migrate(current_time);
+        write_migrated_account(
+            account,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            PlatformConfig::SPACE,
+            &migrated,
+        )?;
+
+        // 发送账户迁移事件
+        emit!(AccountMigrated {
+            account: account.key(),
+            account_type: "platform_config".to_string(),
+            from_version: 1,
+            to_version: PlatformConfig::LAYOUT_VERSION,
+            migrated_by: authority,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 迁移提货券
+     * 
+     * 功能：将旧布局的提货券扩容到当前布局，补充提货券PDA bump并为新增字段填入默认值
+     * 权限：任何人（由付款方支付新增租金）
+     */
+    pub fn migrate_voucher(
+        ctx: Context<MigrateVoucher>,
+    ) -> Result<()> {
+        let account = &ctx.accounts.voucher_account;
+        let legacy: VoucherV1 =
+            load_legacy_account::<Voucher, _>(account, VoucherV1::SPACE, Voucher::SPACE)?;
+
+        // 根据提货券PDA种子计算bump，并校验账户地址
+        let (voucher_address, bump) = Pubkey::find_program_address(
+            &[constants::VOUCHER_SEED, legacy.producer.as_ref(), legacy.id.as_bytes()],
+            &crate::ID,
+        );
+        if voucher_address != account.key() {
+            return Err(SoonShopError::AccountTypeMismatch.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let migrated = legacy.migrate(bump, current_time);
+        write_migrated_account(
+            account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            Voucher::SPACE,
+            &migrated,
+        )?;
+
+        // 发送账户迁移事件
+        emit!(AccountMigrated {
+            account: account.key(),
+            account_type: "voucher".to_string(),
+            from_version: 1,
+            to_version: Voucher::LAYOUT_VERSION,
+            migrated_by: ctx.accounts.payer.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /**
+     * 迁移用户钱包
+     * 
+     * 功能：将旧布局的用户钱包扩容到当前布局，更新钱包版本并为新增字段填入默认值
+     * 权限：任何人（由付款方支付新增租金）
+     */
+    pub fn migrate_user_wallet(
+        ctx: Context<MigrateUserWallet>,
+    ) -> Result<()> {
+        let account = &ctx.accounts.user_wallet;
+        let legacy: UserWalletV1 =
+            load_legacy_account::<UserWallet, _>(account, UserWalletV1::SPACE, UserWallet::SPACE)?;
+        let from_version = legacy.version;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let migrated = legacy.migrate(current_time);
+        write_migrated_account(
+            account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            UserWallet::SPACE,
+            &migrated,
+        )?;
+
+        // 发送账户迁移事件
+        emit!(AccountMigrated {
+            account: account.key(),
+            account_type: "user_wallet".to_string(),
+            from_version,
+            to_version: UserWallet::LAYOUT_VERSION,
+            migrated_by: ctx.accounts.payer.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
+
+// ================================
+// 内部辅助函数
+// ================================
+
+/// 读取可选的程序PDA账户（职工名册、企业评估等），账户尚未创建时返回None
+fn load_optional_account<T: AccountDeserialize>(account: &UncheckedAccount) -> Result<Option<T>> {
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+    if account.owner != &crate::ID {
+        return Err(SoonShopError::AccountOwnerMismatch.into());
+    }
+
+    let data = account.try_borrow_data()?;
+    let value = T::try_deserialize(&mut &data[..])?;
+    Ok(Some(value))
+}
+
+/// 修改可选的程序PDA账户并写回，账户尚未创建时跳过并返回None
+fn update_optional_account<T: AccountDeserialize + AccountSerialize, R>(
+    account: &UncheckedAccount,
+    update: impl FnOnce(&mut T) -> Result<R>,
+) -> Result<Option<R>> {
+    let Some(mut value) = load_optional_account::<T>(account)? else {
+        return Ok(None);
+    };
+    let result = update(&mut value)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)?;
+    Ok(Some(result))
+}
+
+/// 读取待迁移账户的旧布局数据
+///
+/// 校验账户所有者和鉴别器，并按账户数据长度识别布局：当前布局返回已迁移错误，
+/// 长度与旧布局不符时返回版本不兼容错误。
+fn load_legacy_account<T: Discriminator, L: AnchorDeserialize>(
+    account: &UncheckedAccount,
+    legacy_space: usize,
+    current_space: usize,
+) -> Result<L> {
+    if account.owner != &crate::ID {
+        return Err(SoonShopError::AccountOwnerMismatch.into());
+    }
+
+    let data = account.try_borrow_data()?;
+    if !data.starts_with(T::DISCRIMINATOR) {
+        return Err(SoonShopError::AccountTypeMismatch.into());
+    }
+    if data.len() == 8 + current_space {
+        return Err(SoonShopError::AccountAlreadyMigrated.into());
+    }
+    if data.len() != 8 + legacy_space {
+        return Err(SoonShopError::IncompatiblePlatformVersion.into());
+    }
+
+    L::deserialize(&mut &data[T::DISCRIMINATOR.len()..])
+        .map_err(|_| SoonShopError::DeserializationError.into())
+}
+
+/// 将账户扩容到当前布局大小（由付款方补足租金）并写入迁移后的数据
+fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &UncheckedAccount<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    migrated: &T,
+) -> Result<()> {
+    let new_len = 8 + space;
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+    if required_lamports > current_lamports {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: payer.to_account_info(),
+                to: account.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_ctx, required_lamports - current_lamports)?;
+    }
+
+    account.resize(new_len)?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)
+}
+
+/// 校验当前指令之前紧邻的Ed25519预编译指令，确认其由指定公钥对指定消息签名，返回签名
+///
+/// 签名本身由Ed25519预编译程序在交易执行时验证（验证失败整笔交易失败），
+/// 这里只需确认该指令的公钥、消息与预期一致，且数据全部内嵌在该指令中。
+fn verify_ed25519_signature(
+    instructions_sysvar: &UncheckedAccount,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<[u8; constants::SIGNATURE_SIZE]> {
+    let current_index = sysvar_instructions::load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(SoonShopError::InvalidConsumptionProof.into());
+    }
+    let instruction = sysvar_instructions::load_instruction_at_checked(
+        (current_index - 1) as usize,
+        instructions_sysvar,
+    )?;
+    if instruction.program_id != ed25519_program::ID {
+        return Err(SoonShopError::InvalidConsumptionProof.into());
+    }
+
+    parse_ed25519_instruction(&instruction.data, expected_signer, expected_message)
+}
+
+/// 解析Ed25519预编译指令数据，校验公钥和消息后返回签名
+///
+/// 仅接受单个签名且签名、公钥、消息均位于本指令数据中（指令索引为 u16::MAX）。
+fn parse_ed25519_instruction(
+    data: &[u8],
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<[u8; constants::SIGNATURE_SIZE]> {
+    let offsets_end = constants::ED25519_SIGNATURE_OFFSETS_START + constants::ED25519_SIGNATURE_OFFSETS_SIZE;
+    if data.len() < offsets_end || data[0] != 1 {
+        return Err(SoonShopError::InvalidConsumptionProof.into());
+    }
+    let read_u16 = |index: usize| {
+        let start = constants::ED25519_SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    let signature_offset = read_u16(0) as usize;
+    let public_key_offset = read_u16(2) as usize;
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+    if [read_u16(1), read_u16(3), read_u16(6)].iter().any(|&index| index != u16::MAX) {
+        return Err(SoonShopError::InvalidConsumptionProof.into());
+    }
+
+    let signature = data
+        .get(signature_offset..signature_offset + constants::SIGNATURE_SIZE)
+        .ok_or(SoonShopError::InvalidConsumptionProof)?;
+    let public_key = data
+        .get(public_key_offset..public_key_offset + constants::ED25519_PUBKEY_SIZE)
+        .ok_or(SoonShopError::InvalidConsumptionProof)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(SoonShopError::InvalidConsumptionProof)?;
+
+    if public_key != expected_signer.as_ref() || message != expected_message {
+        return Err(SoonShopError::SignatureVerificationFailed.into());
+    }
+
+    let mut signature_bytes = [0u8; constants::SIGNATURE_SIZE];
+    signature_bytes.copy_from_slice(signature);
+    Ok(signature_bytes)
+}
+
+/// 单层上游供应商的链式倍增奖励
+struct UpstreamReward<'info> {
+    producer: Pubkey,
+    token_account: Account<'info, TokenAccount>,
+    amount: u64,
+}
+
+/// 将企业奖励拆分为生产者奖励和各职工分红，返回（生产者奖励，职工分红列表）
+///
+/// 职工分红池按职工分红比例从企业奖励中提取，再按名册权重分配；
+/// 没有职工名册或名册为空时全部归生产者，分配舍入的余数也归生产者。
+fn split_enterprise_reward(
+    enterprise_reward: u64,
+    worker_reward_ratio: u8,
+    worker_roster: Option<&WorkerRoster>,
+) -> Result<(u64, Vec<u64>)> {
+    let worker_shares = match worker_roster {
+        Some(roster) if roster.has_workers() => {
+            let worker_pool = enterprise_reward
+                .checked_mul(worker_reward_ratio as u64)
+                .ok_or(SoonShopError::MathOverflow)?
+                / constants::RATIO_BASE as u64;
+            roster.calculate_shares(worker_pool)?
+        }
+        _ => Vec::new(),
+    };
+    let worker_reward: u64 = worker_shares.iter().sum();
+    let producer_reward = enterprise_reward
+        .checked_sub(worker_reward)
+        .ok_or(SoonShopError::MathUnderflow)?;
+    Ok((producer_reward, worker_shares))
+}
+
+/// 计算链式倍增各层上游奖励：第一层为 first_level_reward，之后每层按 CHAIN_DECAY_RATIO 衰减，共 MAX_CHAIN_LEVELS 层
+fn chain_level_rewards(first_level_reward: u64) -> Result<Vec<u64>> {
+    let mut level_rewards = Vec::with_capacity(constants::MAX_CHAIN_LEVELS as usize);
+    let mut level_reward = first_level_reward;
+    for _ in 0..constants::MAX_CHAIN_LEVELS {
+        level_rewards.push(level_reward);
+        level_reward = level_reward
+            .checked_mul(constants::CHAIN_DECAY_RATIO as u64)
+            .ok_or(SoonShopError::MathOverflow)?
+            / constants::RATIO_BASE as u64;
+    }
+    Ok(level_rewards)
+}
+
+/// 沿提货券的上游链最多向上遍历 MAX_CHAIN_LEVELS 层，每层奖励按衰减比例递减
+///
+/// 每个提货券只关联一个上游提货券，因此上游链是单链；
+/// 每一层需要依次传入上游提货券账户和上游生产者的代币账户；
+/// 上游提货券已关闭时链路在该层终止。
+fn collect_upstream_rewards<'info>(
+    voucher: &Account<'info, Voucher>,
+    chain_accounts: &'info [AccountInfo<'info>],
+    reward_mint: Pubkey,
+    first_level_reward: u64,
+) -> Result<Vec<UpstreamReward<'info>>> {
+    let mut rewards = Vec::new();
+    let mut visited = vec![voucher.key()];
+    let mut next_upstream = voucher.upstream_voucher;
+    let mut cursor = 0;
+
+    for level_reward in chain_level_rewards(first_level_reward)? {
+        let upstream_key = match next_upstream {
+            Some(upstream_key) => upstream_key,
+            None => break,
+        };
+
+        let upstream_info = chain_accounts
+            .get(cursor)
+            .ok_or(SoonShopError::ChainMultiplierPropagationFailed)?;
+        if upstream_info.key() != upstream_key || visited.contains(&upstream_key) {
+            return Err(SoonShopError::ChainMultiplierPropagationFailed.into());
+        }
+        if upstream_info.data_is_empty() {
+            break;
+        }
+
+        let upstream_voucher = Account::<Voucher>::try_from(upstream_info)?;
+        let token_info = chain_accounts
+            .get(cursor + 1)
+            .ok_or(SoonShopError::ChainMultiplierPropagationFailed)?;
+        let token_account = Account::<TokenAccount>::try_from(token_info)?;
+        if token_account.owner != upstream_voucher.producer || token_account.mint != reward_mint {
+            return Err(SoonShopError::InvalidUpstreamConsumption.into());
+        }
+
+        rewards.push(UpstreamReward {
+            producer: upstream_voucher.producer,
+            token_account,
+            amount: level_reward,
+        });
+
+        visited.push(upstream_key);
+        next_upstream = upstream_voucher.upstream_voucher;
+        cursor += 2;
+    }
+
+    Ok(rewards)
+}
+
+/// 以奖励池PDA签名，从奖励池向目标代币账户转出奖励
+fn transfer_from_reward_pool<'info>(
+    token_program: &Program<'info, Token>,
+    reward_pool: &Account<'info, TokenAccount>,
+    reward_pool_authority: &UncheckedAccount<'info>,
+    to: &Account<'info, TokenAccount>,
+    signer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let transfer_instruction = Transfer {
+        from: reward_pool.to_account_info(),
+        to: to.to_account_info(),
+        authority: reward_pool_authority.to_account_info(),
+    };
+
+    let signer = &[signer_seeds];
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        transfer_instruction,
+        signer,
+    );
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// 以铸币权限PDA签名，向目标代币账户铸造平台代币
+fn mint_with_mint_authority<'info>(
+    token_program: &Program<'info, Token>,
+    token_mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    mint_authority: &UncheckedAccount<'info>,
+    mint_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let mint_instruction = MintTo {
+        mint: token_mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: mint_authority.to_account_info(),
+    };
+
+    let bump = [mint_authority_bump];
+    let authority_seeds: &[&[u8]] = &[constants::MINT_AUTHORITY_SEED, &bump];
+    let signer = &[authority_seeds];
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        mint_instruction,
+        signer,
+    );
+
+    token::mint_to(cpi_ctx, amount)
+}
+
+/// 以提货券PDA签名，从提货券托管账户向目标代币账户转出代币
+fn transfer_from_voucher_escrow<'info>(
+    token_program: &Program<'info, Token>,
+    voucher_escrow: &Account<'info, TokenAccount>,
+    voucher: &Account<'info, Voucher>,
+    to: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let transfer_instruction = Transfer {
+        from: voucher_escrow.to_account_info(),
+        to: to.to_account_info(),
+        authority: voucher.to_account_info(),
+    };
+
+    let bump = [voucher.bump];
+    let voucher_seeds: &[&[u8]] = &[
+        constants::VOUCHER_SEED,
+        voucher.producer.as_ref(),
+        voucher.id.as_bytes(),
+        &bump,
+    ];
+    let signer = &[voucher_seeds];
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        transfer_instruction,
+        signer,
+    );
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// 争议期结束后释放消费对应的托管代币：扣除平台手续费后转给商家，手续费转入平台金库，
+/// 结清提货券的未结算消费并计入平台统计，返回平台手续费
+fn release_consumption_escrow<'info>(
+    token_program: &Program<'info, Token>,
+    voucher_escrow: &Account<'info, TokenAccount>,
+    voucher: &mut Account<'info, Voucher>,
+    consumption_record: &mut ConsumptionRecord,
+    merchant_token_account: &Account<'info, TokenAccount>,
+    platform_treasury: &Account<'info, TokenAccount>,
+    platform_config: &mut PlatformConfig,
+) -> Result<u64> {
+    consumption_record.release_escrow(Clock::get()?.unix_timestamp)?;
+
+    let amount = consumption_record.amount;
+    let consumption_fee = platform_config.calculate_fee(amount)?;
+    transfer_from_voucher_escrow(
+        token_program,
+        voucher_escrow,
+        voucher,
+        merchant_token_account,
+        amount - consumption_fee,
+    )?;
+    transfer_from_voucher_escrow(
+        token_program,
+        voucher_escrow,
+        voucher,
+        platform_treasury,
+        consumption_fee,
+    )?;
+    voucher.settle_consumption()?;
+
+    platform_config.update_statistics(StatisticsUpdate {
+        platform_revenue_delta: Some(consumption_fee),
+        transactions_delta: Some(1),
+        ..Default::default()
+    })?;
+
+    Ok(consumption_fee)
+}
+
+/// 读取剩余账户中传入的交易记录，校验归属后按时间范围过滤并按序号排序
+fn load_transaction_records<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    user_wallet: &UserWallet,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+) -> Result<Vec<TransactionRecord>> {
+    let mut records = Vec::with_capacity(remaining_accounts.len());
+    for account_info in remaining_accounts {
+        let record = Account::<TransactionRecord>::try_from(account_info)?;
+        if record.user != user_wallet.owner {
+            return Err(SoonShopError::Unauthorized.into());
+        }
+        if record.in_time_range(start_time, end_time) {
+            records.push(record.into_inner());
+        }
+    }
+    records.sort_by_key(|record| record.sequence);
+
+    Ok(records)
+}
+
+/// 交易历史每页返回记录数，不超过HISTORY_PAGE_SIZE
+fn history_page_limit(limit: Option<u32>) -> usize {
+    limit
+        .map_or(constants::HISTORY_PAGE_SIZE, |limit| limit as usize)
+        .min(constants::HISTORY_PAGE_SIZE)
+}
+
+// ================================
+// 账户结构定义
 // ================================
-// 账户结构定义
-// ================================
 
-/// 初始化平台账户结构
+/// 初始化平台账户结构
+#[derive(Accounts)]
+pub struct InitializePlatform<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PlatformConfig::SPACE,
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: 这是奖励池账户
+    pub reward_pool: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 添加管理员账户结构
+#[derive(Accounts)]
+pub struct AddAdmin<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 移除管理员账户结构
+#[derive(Accounts)]
+pub struct RemoveAdmin<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 提议转移超级管理员账户结构
+#[derive(Accounts)]
+pub struct ProposeSuperAdmin<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+/// 接受超级管理员账户结构
+#[derive(Accounts)]
+pub struct AcceptSuperAdmin<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub new_super_admin: Signer<'info>,
+}
+
+/// 创建管理员多签提案账户结构
+#[derive(Accounts)]
+pub struct CreateAdminProposal<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AdminProposal::SPACE,
+        seeds = [
+            constants::ADMIN_PROPOSAL_SEED,
+            platform_config.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 审批管理员多签提案账户结构
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 设置多签审批阈值账户结构
+#[derive(Accounts)]
+pub struct SetApprovalThreshold<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 紧急暂停账户结构
+#[derive(Accounts)]
+pub struct EmergencyPause<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+/// 恢复系统账户结构
+#[derive(Accounts)]
+pub struct EmergencyResume<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 更新平台参数账户结构
+#[derive(Accounts)]
+pub struct UpdatePlatformParameters<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+}
+
+/// 设置生产者倍增系数账户结构
+#[derive(Accounts)]
+#[instruction(producer: Pubkey)]
+pub struct SetProducerMultiplier<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProducerMultiplier::SPACE,
+        seeds = [constants::MULTIPLIER_OVERRIDE_SEED, producer.as_ref()],
+        bump
+    )]
+    pub multiplier_override: Account<'info, ProducerMultiplier>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 清除生产者倍增系数账户结构
+#[derive(Accounts)]
+pub struct ClearProducerMultiplier<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [constants::MULTIPLIER_OVERRIDE_SEED, multiplier_override.producer.as_ref()],
+        bump
+    )]
+    pub multiplier_override: Account<'info, ProducerMultiplier>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// 查询平台统计信息账户结构
+#[derive(Accounts)]
+pub struct GetPlatformStatistics<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// 提取平台金库账户结构
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    /// CHECK: 平台权限PDA，平台金库代币账户的所有者
+    #[account(seeds = [constants::PLATFORM_AUTHORITY_SEED.as_bytes()], bump)]
+    pub platform_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub destination_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// 创建用户钱包账户结构
+#[derive(Accounts)]
+pub struct CreateUserWallet<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserWallet::SPACE,
+        seeds = [b"user_wallet", user.key().as_ref()],
+        bump
+    )]
+    pub user_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// CHECK: 这是用户的平台代币账户
+    pub platform_token_account: UncheckedAccount<'info>,
+    /// CHECK: 用户账户PDA，尚未注册时使用默认用户类型
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, user.key().as_ref()], bump)]
+    pub user_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 查询钱包余额账户结构
+#[derive(Accounts)]
+pub struct GetWalletBalance<'info> {
+    pub user_wallet: Account<'info, UserWallet>,
+    pub user: Signer<'info>,
+    pub platform_token_account: Account<'info, TokenAccount>,
+}
+
+/// 查询收入历史账户结构
+#[derive(Accounts)]
+pub struct GetIncomeHistory<'info> {
+    pub user_wallet: Account<'info, UserWallet>,
+    pub user: Signer<'info>,
+}
+
+/// 查询支出历史账户结构
+#[derive(Accounts)]
+pub struct GetExpenseHistory<'info> {
+    pub user_wallet: Account<'info, UserWallet>,
+    pub user: Signer<'info>,
+}
+
+/// 查询奖励历史账户结构
+#[derive(Accounts)]
+pub struct GetRewardHistory<'info> {
+    pub user_wallet: Account<'info, UserWallet>,
+    pub user: Signer<'info>,
+}
+
+/// 代币转账账户结构
+#[derive(Accounts)]
+pub struct TransferTokens<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub sender_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub recipient_wallet: Account<'info, UserWallet>,
+    #[account(
+        mut,
+        constraint = sender_token_account.key() == sender_wallet.platform_token_account @ SoonShopError::InvalidTokenAccount,
+        constraint = platform_config.platform_mint == Some(sender_token_account.mint) @ SoonShopError::InvalidTokenMint
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = recipient_token_account.key() == recipient_wallet.platform_token_account @ SoonShopError::InvalidTokenAccount,
+        constraint = platform_config.platform_mint == Some(recipient_token_account.mint) @ SoonShopError::InvalidTokenMint
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    /// CHECK: 平台权限PDA，平台金库代币账户的所有者
+    #[account(seeds = [constants::PLATFORM_AUTHORITY_SEED.as_bytes()], bump)]
+    pub platform_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            sender_wallet.key().as_ref(),
+            sender_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sender_record: Account<'info, TransactionRecord>,
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            recipient_wallet.key().as_ref(),
+            recipient_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub recipient_record: Account<'info, TransactionRecord>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 设置用户类型账户结构
+#[derive(Accounts)]
+pub struct SetUserType<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    /// CHECK: 用户账户PDA，已注册时同步用户类型
+    #[account(
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, user_wallet.owner.as_ref()],
+        bump
+    )]
+    pub user_account: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+/// 冻结钱包账户结构
+#[derive(Accounts)]
+pub struct FreezeWallet<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub authority: Signer<'info>,
+}
+
+/// 暂停钱包账户结构
+#[derive(Accounts)]
+pub struct SuspendWallet<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub authority: Signer<'info>,
+}
+
+/// 解除钱包冻结账户结构
+#[derive(Accounts)]
+pub struct UnfreezeWallet<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub authority: Signer<'info>,
+}
+
+/// 关闭钱包账户结构
+#[derive(Accounts)]
+pub struct CloseUserWallet<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_wallet", user.key().as_ref()],
+        bump
+    )]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub platform_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// 关闭交易记录账户结构
+#[derive(Accounts)]
+pub struct CloseTransactionRecords<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_wallet", user.key().as_ref()],
+        bump
+    )]
+    pub user_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// 设置日转账限额账户结构
+#[derive(Accounts)]
+pub struct SetDailyTransferLimit<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub user: Signer<'info>,
+}
+
+/// 覆盖日转账限额账户结构
+#[derive(Accounts)]
+pub struct OverrideTransferLimit<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub user_wallet: Account<'info, UserWallet>,
+    pub authority: Signer<'info>,
+}
+
+/// 注册用户账户结构
+#[derive(Accounts)]
+pub struct RegisterUserAccount<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserAccount::SPACE,
+        seeds = [constants::USER_ACCOUNT_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 认证用户账户结构
+#[derive(Accounts)]
+pub struct VerifyUserAccount<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, user_account.user_id.as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    /// CHECK: 用户钱包PDA，已创建时同步用户类型
+    #[account(
+        mut,
+        seeds = [b"user_wallet", user_account.user_id.as_ref()],
+        bump
+    )]
+    pub user_wallet: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+/// 更新用户角色账户结构
+#[derive(Accounts)]
+pub struct UpdateUserRole<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, user_account.user_id.as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    pub authority: Signer<'info>,
+}
+
+/// 创建平台代币账户结构
+#[derive(Accounts)]
+pub struct CreatePlatformToken<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, mint::authority = mint_authority)]
+    pub token_mint: Account<'info, Mint>,
+    #[account(mut, token::mint = token_mint)]
+    pub token_vault: Account<'info, TokenAccount>,
+    /// CHECK: 铸币权限PDA，平台代币的铸币权限
+    #[account(seeds = [constants::MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// 铸造代币账户结构
+#[derive(Accounts)]
+pub struct MintTokens<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        constraint = platform_config.platform_mint == Some(token_mint.key()) @ SoonShopError::InvalidTokenAccount
+    )]
+    pub token_mint: Account<'info, Mint>,
+    #[account(mut, token::mint = token_mint)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    /// CHECK: 铸币权限PDA，平台代币的铸币权限
+    #[account(seeds = [constants::MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// 查询铸造预算账户结构
+#[derive(Accounts)]
+pub struct GetMintBudget<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub token_mint: Account<'info, Mint>,
+}
+
+/// 销毁代币账户结构
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub token_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// 发行提货券额度账户结构
+#[derive(Accounts)]
+#[instruction(voucher_id: String, credit_amount: u64, product_info: ProductInfo)]
+pub struct IssueVoucherCredits<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = producer,
+        space = 8 + Voucher::SPACE,
+        seeds = [b"voucher", producer.key().as_ref(), voucher_id.as_bytes()],
+        bump
+    )]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(mut)]
+    pub producer_wallet: Account<'info, UserWallet>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, producer.key().as_ref()], bump)]
+    pub producer_account: Account<'info, UserAccount>,
+    #[account(
+        seeds = [constants::PRICE_DATA_SEED, product_info.category.seed().as_ref()],
+        bump
+    )]
+    pub price_data: Account<'info, PriceData>,
+    #[account(mut)]
+    pub producer_token_account: Account<'info, TokenAccount>,
+    #[account(
+        address = producer_token_account.mint,
+        constraint = platform_config.platform_mint == Some(token_mint.key()) @ SoonShopError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = producer,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = voucher_account
+    )]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub producer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 关闭提货券额度账户结构
+#[derive(Accounts)]
+pub struct CloseVoucherClaim<'info> {
+    /// CHECK: 提货券账户，已关闭时视为剩余额度作废；地址由额度账户PDA种子约束
+    pub voucher_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = holder,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub voucher_claim: Account<'info, VoucherClaim>,
+    #[account(
+        mut,
+        seeds = [b"user_wallet", holder.key().as_ref()],
+        bump
+    )]
+    pub holder_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+}
+
+/// 获取提货券额度账户结构
+#[derive(Accounts)]
+pub struct ClaimVoucherCredits<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(mut)]
+    pub consumer_wallet: Account<'info, UserWallet>,
+    #[account(
+        init_if_needed,
+        payer = consumer,
+        space = 8 + VoucherClaim::SPACE,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), consumer.key().as_ref()],
+        bump
+    )]
+    pub voucher_claim: Account<'info, VoucherClaim>,
+    #[account(seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()], bump)]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            consumer_wallet.key().as_ref(),
+            consumer_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub transaction_record: Account<'info, TransactionRecord>,
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 转让已获取提货券额度账户结构
+#[derive(Accounts)]
+pub struct TransferClaimedCredits<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()], bump)]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub sender_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub recipient_wallet: Account<'info, UserWallet>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), sender.key().as_ref()],
+        bump
+    )]
+    pub sender_claim: Account<'info, VoucherClaim>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = 8 + VoucherClaim::SPACE,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), recipient_wallet.owner.as_ref()],
+        bump
+    )]
+    pub recipient_claim: Account<'info, VoucherClaim>,
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            sender_wallet.key().as_ref(),
+            sender_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sender_record: Account<'info, TransactionRecord>,
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            recipient_wallet.key().as_ref(),
+            recipient_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub recipient_record: Account<'info, TransactionRecord>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 消费提货券额度账户结构
+#[derive(Accounts)]
+pub struct ConsumeVoucherCredits<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(mut)]
+    pub consumer_wallet: Account<'info, UserWallet>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), consumer.key().as_ref()],
+        bump
+    )]
+    pub voucher_claim: Account<'info, VoucherClaim>,
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + ConsumptionRecord::SPACE,
+        seeds = [
+            constants::CONSUMPTION_SEED,
+            voucher_account.key().as_ref(),
+            voucher_account.statistics.consumption_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    #[account(seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()], bump)]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    /// CHECK: 指令系统变量，用于读取同一交易中的Ed25519签名验证指令
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            consumer_wallet.key().as_ref(),
+            consumer_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub transaction_record: Account<'info, TransactionRecord>,
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+    /// CHECK: 这是商家账户
+    pub merchant: UncheckedAccount<'info>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, merchant.key().as_ref()], bump)]
+    pub merchant_account: Account<'info, UserAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 核销提货券额度账户结构
 #[derive(Accounts)]
-pub struct InitializePlatform<'info> {
+pub struct VerifyVoucherConsumption<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    #[account(mut, address = consumption_record.voucher @ SoonShopError::VoucherNotFound)]
+    pub voucher_account: Account<'info, Voucher>,
     #[account(
-        init,
-        payer = authority,
-        space = 8 + PlatformConfig::SPACE,
-        seeds = [b"platform_config"],
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, voucher_account.producer.as_ref()],
         bump
     )]
+    pub producer_account: Account<'info, UserAccount>,
+    pub merchant_wallet: Account<'info, UserWallet>,
+    pub merchant: Signer<'info>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, merchant.key().as_ref()], bump)]
+    pub merchant_account: Account<'info, UserAccount>,
+    /// CHECK: 指令系统变量，用于读取同一交易中的Ed25519签名验证指令
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// 结算超时消费账户结构
+#[derive(Accounts)]
+pub struct SettleExpiredConsumption<'info> {
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
     pub platform_config: Account<'info, PlatformConfig>,
     #[account(mut)]
-    pub authority: Signer<'info>,
-    /// CHECK: 这是奖励池账户
-    pub reward_pool: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    #[account(mut, address = consumption_record.voucher @ SoonShopError::VoucherNotFound)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(mut)]
+    pub consumer_wallet: Account<'info, UserWallet>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), consumption_record.consumer.as_ref()],
+        bump
+    )]
+    pub voucher_claim: Account<'info, VoucherClaim>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
+        bump
+    )]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    /// CHECK: 平台权限PDA，平台金库代币账户的所有者
+    #[account(seeds = [constants::PLATFORM_AUTHORITY_SEED.as_bytes()], bump)]
+    pub platform_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, voucher_account.producer.as_ref()],
+        bump
+    )]
+    pub producer_account: Account<'info, UserAccount>,
+    pub cranker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
-/// 添加管理员账户结构
+/// 发起消费争议账户结构
 #[derive(Accounts)]
-pub struct AddAdmin<'info> {
+pub struct OpenDispute<'info> {
     #[account(mut)]
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    pub consumer: Signer<'info>,
+}
+
+/// 处理消费争议账户结构
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    #[account(mut, address = consumption_record.voucher @ SoonShopError::VoucherNotFound)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(mut)]
+    pub consumer_wallet: Account<'info, UserWallet>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_CLAIM_SEED, voucher_account.key().as_ref(), consumption_record.consumer.as_ref()],
+        bump
+    )]
+    pub voucher_claim: Account<'info, VoucherClaim>,
+    #[account(
+        mut,
+        seeds = [constants::USER_ACCOUNT_SEED, consumption_record.merchant.as_ref()],
+        bump
+    )]
+    pub merchant_account: Account<'info, UserAccount>,
     pub authority: Signer<'info>,
 }
 
-/// 移除管理员账户结构
+/// 分发倍增奖励账户结构
 #[derive(Accounts)]
-pub struct RemoveAdmin<'info> {
+pub struct DistributeMultiplierRewards<'info> {
+    #[account(mut)]
+    pub consumption_record: Account<'info, ConsumptionRecord>,
+    #[account(mut, address = consumption_record.voucher @ SoonShopError::VoucherNotFound)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
+        bump
+    )]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub consumer_wallet: Account<'info, UserWallet>,
+    #[account(mut)]
+    pub producer_wallet: Account<'info, UserWallet>,
     #[account(mut)]
+    pub producer_token_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
     pub platform_config: Account<'info, PlatformConfig>,
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub reward_pool: Account<'info, TokenAccount>,
+    /// CHECK: 奖励池权限PDA，仅用于签名转出奖励
+    #[account(seeds = [constants::REWARD_POOL_SEED], bump)]
+    pub reward_pool_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    /// CHECK: 平台权限PDA，平台金库代币账户的所有者
+    #[account(seeds = [constants::PLATFORM_AUTHORITY_SEED.as_bytes()], bump)]
+    pub platform_authority: UncheckedAccount<'info>,
+    /// CHECK: 生产者职工名册PDA，尚未创建时视为没有职工
+    #[account(seeds = [constants::WORKER_ROSTER_SEED, voucher_account.producer.as_ref()], bump)]
+    pub worker_roster: UncheckedAccount<'info>,
+    /// CHECK: 生产者企业评估PDA，尚未创建时使用平台基础倍增系数
+    #[account(seeds = [constants::EVALUATION_SEED, voucher_account.producer.as_ref()], bump)]
+    pub enterprise_evaluation: UncheckedAccount<'info>,
+    /// CHECK: 生产者倍增系数覆盖PDA，尚未设置时不生效
+    #[account(seeds = [constants::MULTIPLIER_OVERRIDE_SEED, voucher_account.producer.as_ref()], bump)]
+    pub multiplier_override: UncheckedAccount<'info>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, voucher_account.producer.as_ref()], bump)]
+    pub producer_account: Account<'info, UserAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransactionRecord::SPACE,
+        seeds = [
+            constants::TRANSACTION_RECORD_SEED,
+            producer_wallet.key().as_ref(),
+            producer_wallet.record_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub transaction_record: Account<'info, TransactionRecord>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-/// 紧急暂停账户结构
+/// 暂停提货券账户结构
 #[derive(Accounts)]
-pub struct EmergencyPause<'info> {
-    #[account(mut)]
+pub struct PauseVoucher<'info> {
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
     pub authority: Signer<'info>,
 }
 
-/// 恢复系统账户结构
+/// 恢复提货券账户结构
 #[derive(Accounts)]
-pub struct EmergencyResume<'info> {
-    #[account(mut)]
+pub struct ResumeVoucher<'info> {
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
     pub authority: Signer<'info>,
 }
 
-/// 创建用户钱包账户结构
+/// 取消提货券账户结构
 #[derive(Accounts)]
-pub struct CreateUserWallet<'info> {
+pub struct CancelVoucher<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
     #[account(
-        init,
-        payer = user,
-        space = 8 + UserWallet::SPACE,
-        seeds = [b"user_wallet", user.key().as_ref()],
+        mut,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
         bump
     )]
-    pub user_wallet: Account<'info, UserWallet>,
+    pub voucher_escrow: Account<'info, TokenAccount>,
     #[account(mut)]
-    pub user: Signer<'info>,
-    /// CHECK: 这是用户的平台代币账户
-    pub platform_token_account: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
+    pub producer_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    /// CHECK: 平台权限PDA，平台金库代币账户的所有者
+    #[account(seeds = [constants::PLATFORM_AUTHORITY_SEED.as_bytes()], bump)]
+    pub platform_authority: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
-/// 查询钱包余额账户结构
+/// 过期提货券账户结构
 #[derive(Accounts)]
-pub struct GetWalletBalance<'info> {
-    pub user_wallet: Account<'info, UserWallet>,
-    pub user: Signer<'info>,
-    pub platform_token_account: Account<'info, TokenAccount>,
+pub struct ExpireVoucher<'info> {
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
+        bump
+    )]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub producer_token_account: Account<'info, TokenAccount>,
+    pub cranker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
-/// 查询收入历史账户结构
+/// 关闭提货券账户结构
 #[derive(Accounts)]
-pub struct GetIncomeHistory<'info> {
-    pub user_wallet: Account<'info, UserWallet>,
-    pub user: Signer<'info>,
+pub struct CloseVoucher<'info> {
+    #[account(mut, close = producer)]
+    pub voucher_account: Account<'info, Voucher>,
+    #[account(
+        mut,
+        seeds = [constants::VOUCHER_ESCROW_SEED, voucher_account.key().as_ref()],
+        bump
+    )]
+    pub voucher_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub producer_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub producer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
-/// 查询支出历史账户结构
+/// 关联上游提货券账户结构
 #[derive(Accounts)]
-pub struct GetExpenseHistory<'info> {
-    pub user_wallet: Account<'info, UserWallet>,
-    pub user: Signer<'info>,
+pub struct LinkUpstreamVoucher<'info> {
+    #[account(mut)]
+    pub voucher_account: Account<'info, Voucher>,
+    pub upstream_voucher: Account<'info, Voucher>,
+    pub producer: Signer<'info>,
 }
 
-/// 查询奖励历史账户结构
+/// 登记评估员账户结构
 #[derive(Accounts)]
-pub struct GetRewardHistory<'info> {
-    pub user_wallet: Account<'info, UserWallet>,
-    pub user: Signer<'info>,
+#[instruction(evaluator: Pubkey)]
+pub struct RegisterEvaluator<'info> {
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Evaluator::SPACE,
+        seeds = [constants::EVALUATOR_SEED, evaluator.as_ref()],
+        bump
+    )]
+    pub evaluator_account: Account<'info, Evaluator>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// 代币转账账户结构
+/// 移除评估员账户结构
 #[derive(Accounts)]
-pub struct TransferTokens<'info> {
-    #[account(mut)]
-    pub sender_wallet: Account<'info, UserWallet>,
+pub struct RemoveEvaluator<'info> {
     #[account(mut)]
-    pub recipient_wallet: Account<'info, UserWallet>,
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [constants::EVALUATOR_SEED, evaluator_account.evaluator.as_ref()],
+        bump
+    )]
+    pub evaluator_account: Account<'info, Evaluator>,
     #[account(mut)]
-    pub sender_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+}
+
+/// 提交企业评估账户结构
+#[derive(Accounts)]
+pub struct SubmitEvaluation<'info> {
+    #[account(
+        init_if_needed,
+        payer = evaluator,
+        space = 8 + EnterpriseEvaluation::SPACE,
+        seeds = [constants::EVALUATION_SEED, enterprise.key().as_ref()],
+        bump
+    )]
+    pub evaluation: Account<'info, EnterpriseEvaluation>,
+    #[account(
+        mut,
+        seeds = [constants::EVALUATOR_SEED, evaluator.key().as_ref()],
+        bump
+    )]
+    pub evaluator_account: Account<'info, Evaluator>,
+    /// CHECK: 被评估企业（生产者）公钥
+    pub enterprise: UncheckedAccount<'info>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, evaluator.key().as_ref()], bump)]
+    pub evaluator_user_account: Account<'info, UserAccount>,
+    #[account(seeds = [constants::USER_ACCOUNT_SEED, enterprise.key().as_ref()], bump)]
+    pub enterprise_account: Account<'info, UserAccount>,
     #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
-    pub sender: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub evaluator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// 创建平台代币账户结构
+/// 审批企业评估账户结构
 #[derive(Accounts)]
-pub struct CreatePlatformToken<'info> {
+pub struct ApproveEvaluation<'info> {
     pub platform_config: Account<'info, PlatformConfig>,
-    #[account(mut)]
-    pub token_mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub token_vault: Account<'info, TokenAccount>,
-    pub mint_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [constants::EVALUATION_SEED, evaluation.enterprise.as_ref()],
+        bump
+    )]
+    pub evaluation: Account<'info, EnterpriseEvaluation>,
     pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
 }
 
-/// 铸造代币账户结构
+/// 初始化价格数据账户结构
 #[derive(Accounts)]
-pub struct MintTokens<'info> {
+#[instruction(category: ProductCategory)]
+pub struct InitializePriceData<'info> {
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PriceData::SPACE,
+        seeds = [constants::PRICE_DATA_SEED, category.seed().as_ref()],
+        bump
+    )]
+    pub price_data: Account<'info, PriceData>,
     #[account(mut)]
-    pub token_mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
-    pub mint_authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// 销毁代币账户结构
+/// 设置授权喂价方账户结构
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
-    #[account(mut)]
-    pub token_mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
-    pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+pub struct SetPriceFeeders<'info> {
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [constants::PRICE_DATA_SEED, price_data.category.seed().as_ref()],
+        bump
+    )]
+    pub price_data: Account<'info, PriceData>,
+    pub authority: Signer<'info>,
 }
 
-/// 发行提货券额度账户结构
+/// 更新参考价格账户结构
 #[derive(Accounts)]
-#[instruction(voucher_id: String)]
-pub struct IssueVoucherCredits<'info> {
+pub struct UpdatePrice<'info> {
+    #[account(
+        mut,
+        seeds = [constants::PRICE_DATA_SEED, price_data.category.seed().as_ref()],
+        bump
+    )]
+    pub price_data: Account<'info, PriceData>,
+    pub feeder: Signer<'info>,
+}
+
+/// 强制更新参考价格账户结构
+#[derive(Accounts)]
+pub struct ForceUpdatePrice<'info> {
+    #[account(seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [constants::PRICE_DATA_SEED, price_data.category.seed().as_ref()],
+        bump
+    )]
+    pub price_data: Account<'info, PriceData>,
+    pub authority: Signer<'info>,
+}
+
+/// 创建职工名册账户结构
+#[derive(Accounts)]
+pub struct InitializeWorkerRoster<'info> {
     #[account(
         init,
         payer = producer,
-        space = 8 + Voucher::SPACE,
-        seeds = [b"voucher", producer.key().as_ref(), voucher_id.as_bytes()],
+        space = 8 + WorkerRoster::SPACE,
+        seeds = [constants::WORKER_ROSTER_SEED, producer.key().as_ref()],
         bump
     )]
-    pub voucher_account: Account<'info, Voucher>,
-    #[account(mut)]
-    pub producer_wallet: Account<'info, UserWallet>,
+    pub worker_roster: Account<'info, WorkerRoster>,
     #[account(mut)]
     pub producer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// 获取提货券额度账户结构
-#[derive(Accounts)]
-pub struct ClaimVoucherCredits<'info> {
-    #[account(mut)]
-    pub voucher_account: Account<'info, Voucher>,
-    #[account(mut)]
-    pub consumer_wallet: Account<'info, UserWallet>,
-    pub consumer: Signer<'info>,
-}
-
-/// 消费提货券额度账户结构
+/// 更新职工名册账户结构
 #[derive(Accounts)]
-#[instruction(voucher_id: String)]
-pub struct ConsumeVoucherCredits<'info> {
-    #[account(mut)]
-    pub voucher_account: Account<'info, Voucher>,
-    #[account(mut)]
-    pub consumer_wallet: Account<'info, UserWallet>,
+pub struct UpdateWorkerRoster<'info> {
     #[account(
-        init,
-        payer = consumer,
-        space = 8 + ConsumptionRecord::SPACE,
-        seeds = [b"consumption", voucher_id.as_bytes(), consumer.key().as_ref()],
+        mut,
+        seeds = [constants::WORKER_ROSTER_SEED, producer.key().as_ref()],
         bump
     )]
-    pub consumption_record: Account<'info, ConsumptionRecord>,
+    pub worker_roster: Account<'info, WorkerRoster>,
+    pub producer: Signer<'info>,
+}
+
+/// 迁移平台配置账户结构
+#[derive(Accounts)]
+pub struct MigratePlatformConfig<'info> {
+    /// CHECK: 旧布局账户无法按当前布局反序列化，所有者和鉴别器在指令中校验
+    #[account(mut, seeds = [constants::PLATFORM_CONFIG_SEED], bump)]
+    pub platform_config: UncheckedAccount<'info>,
     #[account(mut)]
-    pub consumer: Signer<'info>,
-    /// CHECK: 这是商家账户
-    pub merchant: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// 核销提货券额度账户结构
+/// 迁移提货券账户结构
 #[derive(Accounts)]
-pub struct VerifyVoucherConsumption<'info> {
+pub struct MigrateVoucher<'info> {
+    /// CHECK: 旧布局账户无法按当前布局反序列化，所有者、鉴别器和PDA地址在指令中校验
     #[account(mut)]
-    pub consumption_record: Account<'info, ConsumptionRecord>,
+    pub voucher_account: UncheckedAccount<'info>,
     #[account(mut)]
-    pub merchant_wallet: Account<'info, UserWallet>,
-    pub merchant: Signer<'info>,
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// 分发倍增奖励账户结构
+/// 迁移用户钱包账户结构
 #[derive(Accounts)]
-pub struct DistributeMultiplierRewards<'info> {
+pub struct MigrateUserWallet<'info> {
+    /// CHECK: 旧布局账户无法按当前布局反序列化，所有者和鉴别器在指令中校验
     #[account(mut)]
-    pub consumption_record: Account<'info, ConsumptionRecord>,
+    pub user_wallet: UncheckedAccount<'info>,
     #[account(mut)]
-    pub producer_wallet: Account<'info, UserWallet>,
-    pub platform_config: Account<'info, PlatformConfig>,
-    pub reward_pool: Account<'info, TokenAccount>,
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 // ================================
@@ -976,6 +4803,27 @@ pub struct WalletBalanceInfo {
     pub last_transaction_at: i64,
 }
 
+/// 铸造预算信息
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintBudgetInfo {
+    pub max_supply: u64,
+    pub current_supply: u64,
+    pub remaining_supply: u64,
+    pub epoch: i64,
+    pub epoch_ends_at: i64,
+    pub epoch_consumption_volume: u64,
+    pub epoch_budget: u64,
+    pub epoch_minted: u64,
+    pub remaining_epoch_budget: u64,
+}
+
+/// 平台统计信息
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PlatformStatisticsInfo {
+    pub config: PlatformConfigView,
+    pub statistics: PlatformStatistics,
+}
+
 /// 收入历史信息
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct IncomeHistoryInfo {
@@ -984,6 +4832,9 @@ pub struct IncomeHistoryInfo {
     pub monthly_income: u64,
     pub max_single_income: u64,
     pub income_count: u64,
+    pub records: Vec<IncomeRecord>,
+    /// 下一页起始交易序号，没有更多记录时为None
+    pub next_sequence: Option<u64>,
 }
 
 /// 支出历史信息
@@ -994,6 +4845,9 @@ pub struct ExpenseHistoryInfo {
     pub monthly_expense: u64,
     pub max_single_expense: u64,
     pub expense_count: u64,
+    pub records: Vec<ExpenseRecord>,
+    /// 下一页起始交易序号，没有更多记录时为None
+    pub next_sequence: Option<u64>,
 }
 
 /// 奖励历史信息
@@ -1003,6 +4857,9 @@ pub struct RewardHistoryInfo {
     pub today_rewards: u64,
     pub monthly_rewards: u64,
     pub reward_count: u64,
+    pub records: Vec<RewardRecord>,
+    /// 下一页起始交易序号，没有更多记录时为None
+    pub next_sequence: Option<u64>,
 }
 
 // ================================
@@ -1036,12 +4893,90 @@ pub struct EmergencyPaused {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SuperAdminTransferProposed {
+    pub current_super_admin: Pubkey,
+    pub proposed_super_admin: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SuperAdminTransferred {
+    pub previous_super_admin: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub approver: Pubkey,
+    pub valid_approvals: u8,
+    pub approval_threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalThresholdUpdated {
+    pub approval_threshold: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct EmergencyResumed {
     pub resumed_by: Pubkey,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PlatformParametersUpdated {
+    pub updated_by: Pubkey,
+    pub platform_fee_rate: u16,
+    pub base_multiplier: u8,
+    pub min_multiplier: u8,
+    pub max_multiplier: u8,
+    pub producer_reward_ratio: u8,
+    pub worker_reward_ratio: u8,
+    pub platform_reward_ratio: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProducerMultiplierSet {
+    pub producer: Pubkey,
+    pub multiplier: u8,
+    pub expires_at: Option<i64>,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProducerMultiplierCleared {
+    pub producer: Pubkey,
+    pub cleared_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub withdrawn_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct WalletCreated {
     pub owner: Pubkey,
@@ -1049,11 +4984,81 @@ pub struct WalletCreated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct WalletStatusChanged {
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub previous_status: WalletStatus,
+    pub new_status: WalletStatus,
+    pub reason: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WalletClosed {
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransactionRecordsClosed {
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub closed_count: u32,
+    pub remaining_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferLimitUpdated {
+    pub owner: Pubkey,
+    pub daily_transfer_limit: u64,
+    pub transfer_limit_override: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserTypeUpdated {
+    pub user: Pubkey,
+    pub user_type: UserType,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserAccountRegistered {
+    pub user: Pubkey,
+    pub username: String,
+    pub user_type: UserType,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserAccountVerified {
+    pub user: Pubkey,
+    pub verified: bool,
+    pub verified_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserRoleUpdated {
+    pub user: Pubkey,
+    pub role: UserRole,
+    pub granted: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokenTransferred {
     pub from: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    pub fee: u64,
     pub memo: Option<String>,
     pub timestamp: i64,
 }
@@ -1072,6 +5077,8 @@ pub struct TokensMinted {
     pub mint: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    pub epoch: i64,
+    pub remaining_epoch_budget: u64,
     pub timestamp: i64,
 }
 
@@ -1100,13 +5107,34 @@ pub struct VoucherCreditsClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VoucherCreditsTransferred {
+    pub voucher_id: String,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub sender_balance: u64,
+    pub recipient_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherClaimClosed {
+    pub voucher: Pubkey,
+    pub holder: Pubkey,
+    pub forfeited_balance: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct VoucherCreditsConsumed {
     pub voucher_id: String,
     pub consumer: Pubkey,
     pub merchant: Pubkey,
     pub consume_amount: u64,
+    pub quantity: u64,
     pub consumption_id: String,
+    pub proof_verified: bool,
     pub timestamp: i64,
 }
 
@@ -1115,6 +5143,80 @@ pub struct VoucherConsumptionVerified {
     pub consumption_id: String,
     pub merchant: Pubkey,
     pub quality_score: u8,
+    pub proof_verified: bool,
+    pub producer_reputation: u8,
+    pub dispute_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherPaused {
+    pub voucher_id: String,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherResumed {
+    pub voucher_id: String,
+    pub resumed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherCancelled {
+    pub voucher_id: String,
+    pub cancelled_by: Pubkey,
+    pub refunded_credits: u64,
+    pub cancellation_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherExpired {
+    pub voucher_id: String,
+    pub refunded_credits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherClosed {
+    pub voucher_id: String,
+    pub producer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsumptionAutoSettled {
+    pub consumption_id: String,
+    pub action: AutoSettlementAction,
+    pub settled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsumptionEscrowReleased {
+    pub consumption_id: String,
+    pub merchant_amount: u64,
+    pub consumption_fee: u64,
+    pub released_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsumptionDisputeOpened {
+    pub consumption_id: String,
+    pub consumer: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsumptionDisputeResolved {
+    pub consumption_id: String,
+    pub arbiter: Pubkey,
+    pub outcome: DisputeOutcome,
+    pub merchant_reputation: u8,
     pub timestamp: i64,
 }
 
@@ -1123,8 +5225,107 @@ pub struct MultiplierRewardsDistributed {
     pub consumption_id: String,
     pub producer: Pubkey,
     pub producer_reward: u64,
+    pub worker_reward: u64,
+    pub upstream_reward: u64,
     pub platform_reward: u64,
     pub quality_score: u8,
+    pub multiplier: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpstreamVoucherLinked {
+    pub voucher_id: String,
+    pub producer: Pubkey,
+    pub upstream_voucher: Pubkey,
+    pub upstream_producer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvaluatorRegistered {
+    pub evaluator: Pubkey,
+    pub registered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvaluatorRemoved {
+    pub evaluator: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvaluationSubmitted {
+    pub enterprise: Pubkey,
+    pub evaluator: Pubkey,
+    pub evaluation_period: String,
+    pub overall_score: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvaluationReviewed {
+    pub enterprise: Pubkey,
+    pub approver: Pubkey,
+    pub approved: bool,
+    pub overall_score: u8,
+    pub multiplier: u8,
+    pub valid_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceDataInitialized {
+    pub category: ProductCategory,
+    pub price_data: Pubkey,
+    pub feeder_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedersUpdated {
+    pub category: ProductCategory,
+    pub feeder_count: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceUpdated {
+    pub category: ProductCategory,
+    pub price: u64,
+    pub confidence: u32,
+    pub feeder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceForceUpdated {
+    pub category: ProductCategory,
+    pub previous_price: u64,
+    pub price: u64,
+    pub confidence: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub account_type: String,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WorkerRosterUpdated {
+    pub producer: Pubkey,
+    pub worker_count: u8,
+    pub total_weight: u64,
     pub timestamp: i64,
 }
 
@@ -1150,4 +5351,139 @@ pub struct PlatformConfigView {
     pub admin_count: u8,
     pub created_at: i64,
     pub last_updated: i64,
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED25519_DATA_START: usize = constants::ED25519_SIGNATURE_OFFSETS_START + constants::ED25519_SIGNATURE_OFFSETS_SIZE;
+
+    fn ed25519_instruction_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let public_key_offset = ED25519_DATA_START;
+        let signature_offset = public_key_offset + constants::ED25519_PUBKEY_SIZE;
+        let message_offset = signature_offset + constants::SIGNATURE_SIZE;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset as u16,
+            u16::MAX,
+            public_key_offset as u16,
+            u16::MAX,
+            message_offset as u16,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7u8; constants::SIGNATURE_SIZE]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    fn write_u16(data: &mut [u8], index: usize, value: u16) {
+        let start = constants::ED25519_SIGNATURE_OFFSETS_START + index * 2;
+        data[start..start + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn parse_ed25519_instruction_returns_signature() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_instruction_data(&signer, b"delivery");
+
+        let signature = parse_ed25519_instruction(&data, &signer, b"delivery").unwrap();
+        assert_eq!(signature, [7u8; constants::SIGNATURE_SIZE]);
+    }
+
+    #[test]
+    fn parse_ed25519_instruction_rejects_other_signer_or_message() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_instruction_data(&signer, b"delivery");
+
+        assert!(parse_ed25519_instruction(&data, &Pubkey::new_unique(), b"delivery").is_err());
+        assert!(parse_ed25519_instruction(&data, &signer, b"delivered").is_err());
+    }
+
+    #[test]
+    fn parse_ed25519_instruction_requires_single_inline_signature() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_instruction_data(&signer, b"delivery");
+
+        let mut multiple = data.clone();
+        multiple[0] = 2;
+        assert!(parse_ed25519_instruction(&multiple, &signer, b"delivery").is_err());
+
+        for index in [1, 3, 6] {
+            let mut external = data.clone();
+            write_u16(&mut external, index, 0);
+            assert!(parse_ed25519_instruction(&external, &signer, b"delivery").is_err());
+        }
+    }
+
+    #[test]
+    fn parse_ed25519_instruction_rejects_out_of_bounds_offsets() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_instruction_data(&signer, b"delivery");
+
+        assert!(parse_ed25519_instruction(&data[..ED25519_DATA_START - 1], &signer, b"delivery").is_err());
+        assert!(parse_ed25519_instruction(&data[..data.len() - 1], &signer, b"delivery").is_err());
+
+        let mut bad_signature = data.clone();
+        write_u16(&mut bad_signature, 0, data.len() as u16);
+        assert!(parse_ed25519_instruction(&bad_signature, &signer, b"delivery").is_err());
+
+        let mut bad_public_key = data.clone();
+        write_u16(&mut bad_public_key, 2, u16::MAX);
+        assert!(parse_ed25519_instruction(&bad_public_key, &signer, b"delivery").is_err());
+    }
+
+    fn roster(weights: &[u32]) -> WorkerRoster {
+        WorkerRoster {
+            producer: Pubkey::new_unique(),
+            workers: weights
+                .iter()
+                .map(|&weight| WorkerShare { worker: Pubkey::new_unique(), weight })
+                .collect(),
+            total_weight: weights.iter().map(|&weight| weight as u64).sum(),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn split_enterprise_reward_pays_worker_ratio_by_weight() {
+        let (producer_reward, worker_shares) =
+            split_enterprise_reward(1_000, 30, Some(&roster(&[1, 2]))).unwrap();
+        assert_eq!(worker_shares, vec![100, 200]);
+        assert_eq!(producer_reward, 700);
+    }
+
+    #[test]
+    fn split_enterprise_reward_gives_rounding_remainder_to_producer() {
+        let (producer_reward, worker_shares) =
+            split_enterprise_reward(1_001, 50, Some(&roster(&[1, 1, 1]))).unwrap();
+        assert_eq!(worker_shares, vec![166, 166, 166]);
+        assert_eq!(producer_reward, 503);
+        assert_eq!(producer_reward + worker_shares.iter().sum::<u64>(), 1_001);
+    }
+
+    #[test]
+    fn split_enterprise_reward_without_workers_goes_to_producer() {
+        assert_eq!(split_enterprise_reward(1_000, 30, None).unwrap(), (1_000, Vec::new()));
+        assert_eq!(split_enterprise_reward(1_000, 30, Some(&roster(&[]))).unwrap(), (1_000, Vec::new()));
+    }
+
+    #[test]
+    fn chain_level_rewards_decay_per_level() {
+        let rewards = chain_level_rewards(10_000).unwrap();
+        assert_eq!(rewards, vec![10_000, 9_000, 8_100, 7_290, 6_561]);
+    }
+
+    #[test]
+    fn chain_level_rewards_stop_at_max_chain_levels() {
+        let rewards = chain_level_rewards(1_000_000).unwrap();
+        assert_eq!(rewards.len(), constants::MAX_CHAIN_LEVELS as usize);
+        assert!(chain_level_rewards(0).unwrap().iter().all(|&reward| reward == 0));
+    }
+}
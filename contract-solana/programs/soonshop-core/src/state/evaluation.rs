@@ -0,0 +1,302 @@
+/**
+ * SoonShop核心智能合约企业评估状态模块
+ *
+ * 本模块定义了企业评估相关的状态结构体，包括：
+ * - 评估员信息
+ * - 企业评估记录
+ * - 评估分数
+ */
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+// ================================
+// 评估员账户
+// ================================
+
+/**
+ * 评估员信息
+ *
+ * 由平台管理员登记，只有已登记且处于活跃状态的评估员才能提交企业评估
+ */
+#[account]
+#[derive(Debug)]
+pub struct Evaluator {
+    /// 评估员公钥
+    pub evaluator: Pubkey,
+
+    /// 登记管理员
+    pub registered_by: Pubkey,
+
+    /// 是否活跃
+    pub is_active: bool,
+
+    /// 已提交评估次数
+    pub evaluation_count: u64,
+
+    /// 登记时间
+    pub registered_at: i64,
+
+    /// 更新时间
+    pub updated_at: i64,
+}
+
+// ================================
+// 企业评估账户
+// ================================
+
+/**
+ * 企业评估信息
+ *
+ * 每个生产者一个评估账户，保存最近一次提交的评估以及最近一次批准的评估结果，
+ * 批准结果在有效期内决定该生产者的倍增系数
+ */
+#[account]
+#[derive(Debug)]
+pub struct EnterpriseEvaluation {
+    /// 被评估企业（生产者）公钥
+    pub enterprise: Pubkey,
+
+    /// 最近提交评估的评估员
+    pub evaluator: Pubkey,
+
+    /// 评估周期
+    pub evaluation_period: String,
+
+    /// 评估分数
+    pub scores: EvaluationScores,
+
+    /// 综合评分
+    pub overall_score: u8,
+
+    /// 评估状态
+    pub status: EvaluationStatus,
+
+    /// 提交时间
+    pub submitted_at: i64,
+
+    /// 评语
+    pub comments: String,
+
+    /// 最近批准的评估周期
+    pub approved_period: String,
+
+    /// 最近批准的综合评分
+    pub approved_score: u8,
+
+    /// 批准时间
+    pub approved_at: Option<i64>,
+
+    /// 批准管理员
+    pub approver: Option<Pubkey>,
+
+    /// 批准评估对应的倍增系数
+    pub multiplier_impact: u8,
+
+    /// 批准评估有效期截止时间
+    pub valid_until: i64,
+
+    /// 累计评估次数
+    pub evaluation_count: u32,
+}
+
+/**
+ * 评估分数
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct EvaluationScores {
+    /// 生产能力
+    pub production_capacity: u8,
+
+    /// 产品质量
+    pub product_quality: u8,
+
+    /// 服务水平
+    pub service_level: u8,
+
+    /// 社会责任
+    pub social_responsibility: u8,
+
+    /// 创新能力
+    pub innovation_capability: u8,
+}
+
+/**
+ * 评估状态枚举
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Default)]
+pub enum EvaluationStatus {
+    /// 待审批
+    #[default]
+    Pending,
+    /// 已批准
+    Approved,
+    /// 已驳回
+    Rejected,
+}
+
+// ================================
+// 实现
+// ================================
+
+impl Evaluator {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE * 2  // evaluator, registered_by
+        + BOOL_SIZE        // is_active
+        + U64_SIZE         // evaluation_count
+        + I64_SIZE * 2;    // registered_at, updated_at
+
+    /// 初始化评估员
+    pub fn initialize(&mut self, evaluator: Pubkey, registered_by: Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.evaluator = evaluator;
+        self.registered_by = registered_by;
+        self.is_active = true;
+        self.evaluation_count = 0;
+        self.registered_at = current_time;
+        self.updated_at = current_time;
+
+        Ok(())
+    }
+}
+
+impl EnterpriseEvaluation {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE * 2                        // enterprise, evaluator
+        + STRING_PREFIX_SIZE + 20                // evaluation_period
+        + EvaluationScores::SPACE                // scores
+        + U8_SIZE                                // overall_score
+        + 1                                      // status enum
+        + I64_SIZE                               // submitted_at
+        + STRING_PREFIX_SIZE + MAX_COMMENT_LENGTH // comments
+        + STRING_PREFIX_SIZE + 20                // approved_period
+        + U8_SIZE                                // approved_score
+        + OPTION_FLAG_SIZE + I64_SIZE            // approved_at
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE         // approver
+        + U8_SIZE                                // multiplier_impact
+        + I64_SIZE                               // valid_until
+        + U32_SIZE;                              // evaluation_count
+
+    /// 提交新的评估
+    pub fn submit(
+        &mut self,
+        enterprise: Pubkey,
+        evaluator: Pubkey,
+        evaluation_period: String,
+        scores: EvaluationScores,
+        comments: String,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            evaluation_period.len() <= 20 && !evaluation_period.is_empty(),
+            crate::errors::SoonShopError::InvalidEvaluationDetails
+        );
+        require!(
+            comments.len() <= MAX_COMMENT_LENGTH,
+            crate::errors::SoonShopError::StringTooLong
+        );
+        scores.validate()?;
+
+        if self.evaluation_count > 0 {
+            // 已有待审批评估时不能重复提交
+            if self.status == EvaluationStatus::Pending {
+                return Err(crate::errors::SoonShopError::EvaluationAlreadyExists.into());
+            }
+
+            // 同一周期不能重复评估，且两次评估之间需间隔最小评估周期
+            if self.approved_period == evaluation_period {
+                return Err(crate::errors::SoonShopError::DuplicateEvaluationPeriod.into());
+            }
+            if let Some(approved_at) = self.approved_at {
+                if current_time < approved_at + MIN_EVALUATION_INTERVAL {
+                    return Err(crate::errors::SoonShopError::DuplicateEvaluationPeriod.into());
+                }
+            }
+        }
+
+        self.enterprise = enterprise;
+        self.evaluator = evaluator;
+        self.evaluation_period = evaluation_period;
+        self.overall_score = scores.overall();
+        self.scores = scores;
+        self.status = EvaluationStatus::Pending;
+        self.submitted_at = current_time;
+        self.comments = comments;
+        self.evaluation_count += 1;
+
+        Ok(())
+    }
+
+    /// 批准待审批的评估
+    pub fn approve(&mut self, approver: Pubkey, multiplier: u8) -> Result<()> {
+        if self.status != EvaluationStatus::Pending {
+            return Err(crate::errors::SoonShopError::EvaluationNotFound.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.status = EvaluationStatus::Approved;
+        self.approved_period = self.evaluation_period.clone();
+        self.approved_score = self.overall_score;
+        self.approved_at = Some(current_time);
+        self.approver = Some(approver);
+        self.multiplier_impact = multiplier;
+        self.valid_until = current_time + EVALUATION_VALIDITY_PERIOD;
+
+        Ok(())
+    }
+
+    /// 驳回待审批的评估，保留此前批准的结果
+    pub fn reject(&mut self) -> Result<()> {
+        if self.status != EvaluationStatus::Pending {
+            return Err(crate::errors::SoonShopError::EvaluationNotFound.into());
+        }
+
+        self.status = EvaluationStatus::Rejected;
+        Ok(())
+    }
+
+    /// 获取当前有效的倍增系数（无已批准评估或已过有效期时返回None）
+    pub fn effective_multiplier(&self, current_time: i64) -> Option<u8> {
+        match self.approved_at {
+            Some(_) if current_time <= self.valid_until => Some(self.multiplier_impact),
+            _ => None,
+        }
+    }
+}
+
+impl EvaluationScores {
+    pub const SPACE: usize = U8_SIZE * 5;
+
+    /// 校验各项分数范围
+    pub fn validate(&self) -> Result<()> {
+        let scores = [
+            self.production_capacity,
+            self.product_quality,
+            self.service_level,
+            self.social_responsibility,
+            self.innovation_capability,
+        ];
+
+        if scores.iter().any(|&score| !(MIN_EVALUATION_SCORE..=MAX_EVALUATION_SCORE).contains(&score)) {
+            return Err(crate::errors::SoonShopError::InvalidEvaluationScore.into());
+        }
+
+        Ok(())
+    }
+
+    /// 计算综合评分（各项平均）
+    pub fn overall(&self) -> u8 {
+        let total = self.production_capacity as u32
+            + self.product_quality as u32
+            + self.service_level as u32
+            + self.social_responsibility as u32
+            + self.innovation_capability as u32;
+
+        (total / 5) as u8
+    }
+}
@@ -0,0 +1,214 @@
+/**
+ * 账户布局迁移
+ *
+ * 保存各账户的历史布局（仅用于反序列化旧账户数据），并负责将旧布局转换为当前布局：
+ * 旧布局中已有的字段原样保留，之后追加的字段填入默认值
+ */
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use super::platform::*;
+use super::wallet::*;
+use super::voucher::*;
+
+// ================================
+// 第1版账户布局
+// ================================
+
+/**
+ * 第1版平台配置布局
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PlatformConfigV1 {
+    pub super_admin: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub base_multiplier: u8,
+    pub max_multiplier: u8,
+    pub min_multiplier: u8,
+    pub platform_fee_rate: u16,
+    pub reward_pool: Pubkey,
+    pub status: PlatformStatus,
+    pub is_emergency_paused: bool,
+    pub emergency_pause_time: Option<i64>,
+    pub daily_emergency_pauses: u8,
+    pub last_emergency_reset_day: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub version: String,
+    pub statistics: PlatformStatistics,
+}
+
+/**
+ * 第1版提货券布局
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VoucherV1 {
+    pub id: String,
+    pub producer: Pubkey,
+    pub product_info: ProductInfo,
+    pub total_credits: u64,
+    pub claimed_credits: u64,
+    pub consumed_credits: u64,
+    pub status: VoucherStatus,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+    pub updated_at: i64,
+    pub config: VoucherConfig,
+    pub claim_restrictions: ClaimRestrictions,
+    pub statistics: VoucherStatistics,
+}
+
+/**
+ * 第1版用户钱包布局
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UserWalletV1 {
+    pub owner: Pubkey,
+    pub platform_token_account: Pubkey,
+    pub total_income: u64,
+    pub total_expense: u64,
+    pub total_rewards: u64,
+    pub transaction_count: u64,
+    pub last_transaction_at: i64,
+    pub status: WalletStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub version: u8,
+    pub permissions: WalletPermissions,
+    pub statistics: WalletStatistics,
+}
+
+// ================================
+// 迁移实现
+// ================================
+
+impl PlatformConfigV1 {
+    /// 第1版账户数据空间（用于识别旧布局账户）
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + VEC_PREFIX_SIZE + MAX_ADMINS * PUBKEY_SIZE
+        + U8_SIZE * 3
+        + U16_SIZE
+        + PUBKEY_SIZE
+        + 1
+        + BOOL_SIZE
+        + OPTION_FLAG_SIZE + I64_SIZE
+        + U8_SIZE
+        + I64_SIZE
+        + I64_SIZE * 2
+        + STRING_PREFIX_SIZE + 20
+        + PlatformStatistics::SPACE;
+
+    /// 转换为当前布局
+    pub fn migrate(self, current_time: i64) -> PlatformConfig {
+        PlatformConfig {
+            super_admin: self.super_admin,
+            admins: self.admins,
+            base_multiplier: self.base_multiplier,
+            max_multiplier: self.max_multiplier,
+            min_multiplier: self.min_multiplier,
+            platform_fee_rate: self.platform_fee_rate,
+            reward_pool: self.reward_pool,
+            status: self.status,
+            is_emergency_paused: self.is_emergency_paused,
+            emergency_pause_time: self.emergency_pause_time,
+            daily_emergency_pauses: self.daily_emergency_pauses,
+            last_emergency_reset_day: self.last_emergency_reset_day,
+            created_at: self.created_at,
+            updated_at: current_time,
+            version: self.version,
+            statistics: self.statistics,
+            producer_reward_ratio: PRODUCER_REWARD_RATIO as u8,
+            worker_reward_ratio: WORKER_REWARD_RATIO as u8,
+            platform_reward_ratio: PLATFORM_REWARD_RATIO as u8,
+            auto_settlement_action: AutoSettlementAction::default(),
+            total_transactions: 0,
+            transfer_limit_tiers: TransferLimitTiers::default(),
+            pending_super_admin: None,
+            approval_threshold: DEFAULT_APPROVAL_THRESHOLD,
+            proposal_count: 0,
+            layout_version: PlatformConfig::LAYOUT_VERSION,
+            reputation_settings: ReputationSettings::default(),
+            inflation_settings: InflationSettings::default(),
+            mint_epoch: MintEpoch::default(),
+            platform_mint: None,
+        }
+    }
+}
+
+impl VoucherV1 {
+    /// 第1版账户数据空间（用于识别旧布局账户）
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + STRING_PREFIX_SIZE + 50
+        + PUBKEY_SIZE
+        + ProductInfo::SPACE
+        + U64_SIZE * 3
+        + 1
+        + I64_SIZE
+        + OPTION_FLAG_SIZE + I64_SIZE
+        + I64_SIZE
+        + VoucherConfig::SPACE
+        + ClaimRestrictions::SPACE
+        + VoucherStatistics::SPACE;
+
+    /// 转换为当前布局，bump由调用方根据提货券PDA种子计算
+    pub fn migrate(self, bump: u8, current_time: i64) -> Voucher {
+        Voucher {
+            id: self.id,
+            producer: self.producer,
+            product_info: self.product_info,
+            total_credits: self.total_credits,
+            claimed_credits: self.claimed_credits,
+            consumed_credits: self.consumed_credits,
+            status: self.status,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            updated_at: current_time,
+            config: self.config,
+            claim_restrictions: self.claim_restrictions,
+            statistics: self.statistics,
+            upstream_voucher: None,
+            outstanding_consumptions: 0,
+            bump,
+            layout_version: Voucher::LAYOUT_VERSION,
+        }
+    }
+}
+
+impl UserWalletV1 {
+    /// 第1版账户数据空间（用于识别旧布局账户）
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE * 2
+        + U64_SIZE * 6
+        + 1
+        + I64_SIZE * 2
+        + U8_SIZE
+        + WalletPermissions::SPACE
+        + WalletStatistics::SPACE;
+
+    /// 转换为当前布局
+    pub fn migrate(self, current_time: i64) -> UserWallet {
+        UserWallet {
+            owner: self.owner,
+            platform_token_account: self.platform_token_account,
+            total_income: self.total_income,
+            total_expense: self.total_expense,
+            total_rewards: self.total_rewards,
+            transaction_count: self.transaction_count,
+            last_transaction_at: self.last_transaction_at,
+            status: self.status,
+            created_at: self.created_at,
+            updated_at: current_time,
+            version: UserWallet::LAYOUT_VERSION,
+            permissions: self.permissions,
+            statistics: self.statistics,
+            user_type: UserType::default(),
+            record_count: 0,
+            status_reason: String::new(),
+            status_updated_at: self.updated_at,
+            transfer_limit_override: None,
+            open_voucher_claims: 0,
+            open_consumptions: 0,
+        }
+    }
+}
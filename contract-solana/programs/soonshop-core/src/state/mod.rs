@@ -1,14 +1,24 @@
 /**
  * SoonShop核心智能合约状态模块
  * 
- * 本模块导出平台管理、钱包、提货券相关的状态结构体和枚举
+ * 本模块导出平台管理、钱包、提货券、职工名册、企业评估、价格监控、账户迁移、用户账户相关的状态结构体和枚举
  */
 
 pub mod platform;
 pub mod wallet;
 pub mod voucher;
+pub mod worker;
+pub mod evaluation;
+pub mod price;
+pub mod migration;
+pub mod user;
 
 // 重新导出主要类型
 pub use platform::*;
 pub use wallet::*;
-pub use voucher::*; 
\ No newline at end of file
+pub use voucher::*;
+pub use worker::*;
+pub use evaluation::*;
+pub use price::*;
+pub use migration::*;
+pub use user::*; 
\ No newline at end of file
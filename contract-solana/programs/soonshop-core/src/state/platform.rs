@@ -9,6 +9,7 @@
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use super::voucher::UserType;
 
 // ================================
 // 平台配置账户
@@ -69,6 +70,242 @@ pub struct PlatformConfig {
     
     /// 系统统计信息
     pub statistics: PlatformStatistics,
+    
+    /// 生产者奖励比例（企业份额中的百分比）
+    pub producer_reward_ratio: u8,
+    
+    /// 职工奖励比例（企业份额中的百分比）
+    pub worker_reward_ratio: u8,
+    
+    /// 平台奖励比例（倍增奖励中的百分比）
+    pub platform_reward_ratio: u8,
+    
+    /// 超时未确认消费的自动结算方式
+    pub auto_settlement_action: AutoSettlementAction,
+    
+    /// 总交易次数
+    pub total_transactions: u64,
+    
+    /// 按用户类型划分的默认日转账限额
+    pub transfer_limit_tiers: TransferLimitTiers,
+    
+    /// 待接受的超级管理员
+    pub pending_super_admin: Option<Pubkey>,
+    
+    /// 特权操作所需的管理员审批数量
+    pub approval_threshold: u8,
+    
+    /// 已创建的多签提案数量（下一个提案的编号）
+    pub proposal_count: u64,
+    
+    /// 账户布局版本
+    pub layout_version: u8,
+    
+    /// 信誉门槛及低信誉惩罚设置
+    pub reputation_settings: ReputationSettings,
+    
+    /// 通胀控制设置
+    pub inflation_settings: InflationSettings,
+    
+    /// 当前铸造周期状态
+    pub mint_epoch: MintEpoch,
+    
+    /// 平台代币铸币账户（创建后不可更改）
+    pub platform_mint: Option<Pubkey>,
+}
+
+/**
+ * 按用户类型划分的日转账限额
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct TransferLimitTiers {
+    /// 普通用户
+    pub regular: u64,
+    /// VIP用户
+    pub vip: u64,
+    /// 企业用户
+    pub enterprise: u64,
+    /// 政府用户
+    pub government: u64,
+    /// 学生用户
+    pub student: u64,
+    /// 老年用户
+    pub senior: u64,
+    /// 残疾人用户
+    pub disabled: u64,
+}
+
+impl Default for TransferLimitTiers {
+    fn default() -> Self {
+        Self {
+            regular: DEFAULT_DAILY_TRANSFER_LIMIT,
+            vip: VIP_DAILY_TRANSFER_LIMIT,
+            enterprise: ENTERPRISE_DAILY_TRANSFER_LIMIT,
+            government: ENTERPRISE_DAILY_TRANSFER_LIMIT,
+            student: DEFAULT_DAILY_TRANSFER_LIMIT,
+            senior: DEFAULT_DAILY_TRANSFER_LIMIT,
+            disabled: DEFAULT_DAILY_TRANSFER_LIMIT,
+        }
+    }
+}
+
+/**
+ * 信誉门槛及低信誉惩罚设置
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ReputationSettings {
+    /// 发行提货券所需的最低信誉评分
+    pub min_issue_reputation: u8,
+    /// 信誉评分低于该值的生产者按惩罚比例发放倍增奖励
+    pub penalty_threshold: u8,
+    /// 低信誉生产者的倍增奖励发放比例（百分比）
+    pub penalty_reward_ratio: u8,
+}
+
+impl Default for ReputationSettings {
+    fn default() -> Self {
+        Self {
+            min_issue_reputation: DEFAULT_MIN_ISSUE_REPUTATION,
+            penalty_threshold: DEFAULT_REPUTATION_PENALTY_THRESHOLD,
+            penalty_reward_ratio: DEFAULT_REPUTATION_PENALTY_RATIO,
+        }
+    }
+}
+
+impl ReputationSettings {
+    pub const SPACE: usize = U8_SIZE * 3;
+
+    /// 校验设置取值范围
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_issue_reputation <= MAX_REPUTATION_SCORE
+                && self.penalty_threshold <= MAX_REPUTATION_SCORE
+                && self.penalty_reward_ratio as u32 <= RATIO_BASE,
+            crate::errors::SoonShopError::InvalidPlatformConfig
+        );
+        Ok(())
+    }
+
+    /// 按生产者信誉评分对倍增奖励施加惩罚
+    pub fn apply_penalty(&self, reward: u64, reputation_score: u8) -> Result<u64> {
+        if reputation_score >= self.penalty_threshold {
+            return Ok(reward);
+        }
+        Ok(reward
+            .checked_mul(self.penalty_reward_ratio as u64)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?
+            / RATIO_BASE as u64)
+    }
+}
+
+/**
+ * 通胀控制设置
+ * 
+ * 铸造总量不得超过最大供应量；每个周期的铸造预算为基础预算加上本周期及上一周期消费额按最高倍增系数折算的奖励上限
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct InflationSettings {
+    /// 平台代币最大供应量
+    pub max_supply: u64,
+    /// 铸造周期长度（秒）
+    pub epoch_duration: i64,
+    /// 每个周期的基础铸造预算
+    pub base_epoch_budget: u64,
+}
+
+impl Default for InflationSettings {
+    fn default() -> Self {
+        Self {
+            max_supply: DEFAULT_MAX_TOKEN_SUPPLY,
+            epoch_duration: DEFAULT_MINT_EPOCH_DURATION,
+            base_epoch_budget: DEFAULT_BASE_EPOCH_MINT_BUDGET,
+        }
+    }
+}
+
+impl InflationSettings {
+    pub const SPACE: usize = U64_SIZE + I64_SIZE + U64_SIZE;
+
+    /// 校验设置取值范围
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_supply > 0 && self.epoch_duration > 0,
+            crate::errors::SoonShopError::InvalidPlatformConfig
+        );
+        Ok(())
+    }
+
+    /// 检查铸造后总供应量不超过上限
+    pub fn check_supply(&self, current_supply: u64, amount: u64) -> Result<()> {
+        let new_supply = current_supply
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        require!(
+            new_supply <= self.max_supply,
+            crate::errors::SoonShopError::InflationRateExceeded
+        );
+        Ok(())
+    }
+}
+
+/**
+ * 铸造周期状态
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct MintEpoch {
+    /// 周期编号（当前时间除以周期长度）
+    pub epoch: i64,
+    /// 本周期消费额
+    pub consumption_volume: u64,
+    /// 上一周期消费额
+    pub previous_consumption_volume: u64,
+    /// 本周期已铸造数量
+    pub minted: u64,
+}
+
+impl MintEpoch {
+    pub const SPACE: usize = I64_SIZE + U64_SIZE * 3;
+
+    /// 进入新周期时结转上一周期消费额并清零本周期计数
+    pub fn roll(&mut self, current_time: i64, epoch_duration: i64) {
+        let epoch = current_time / epoch_duration;
+        if epoch == self.epoch {
+            return;
+        }
+        self.previous_consumption_volume = if epoch == self.epoch + 1 {
+            self.consumption_volume
+        } else {
+            0
+        };
+        self.epoch = epoch;
+        self.consumption_volume = 0;
+        self.minted = 0;
+    }
+
+    /// 本周期铸造预算
+    pub fn budget(&self, base_budget: u64, max_multiplier: u8) -> u64 {
+        self.consumption_volume
+            .saturating_add(self.previous_consumption_volume)
+            .saturating_mul(max_multiplier as u64)
+            .saturating_add(base_budget)
+    }
+}
+
+impl TransferLimitTiers {
+    pub const SPACE: usize = U64_SIZE * 7;
+
+    /// 获取指定用户类型的默认日转账限额
+    pub fn limit_for(&self, user_type: &UserType) -> u64 {
+        match user_type {
+            UserType::Regular => self.regular,
+            UserType::VIP => self.vip,
+            UserType::Enterprise => self.enterprise,
+            UserType::Government => self.government,
+            UserType::Student => self.student,
+            UserType::Senior => self.senior,
+            UserType::Disabled => self.disabled,
+        }
+    }
 }
 
 /**
@@ -143,6 +380,18 @@ pub enum PlatformStatus {
     Deactivated,
 }
 
+/**
+ * 自动结算方式枚举
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Default)]
+pub enum AutoSettlementAction {
+    /// 自动确认（使用默认质量评分）
+    #[default]
+    Confirm,
+    /// 自动取消并退回额度
+    Cancel,
+}
+
 impl Default for PlatformStatus {
     fn default() -> Self {
         PlatformStatus::Initializing
@@ -168,7 +417,22 @@ impl PlatformConfig {
         + I64_SIZE     // last_emergency_reset_day
         + I64_SIZE * 2 // created_at, updated_at
         + STRING_PREFIX_SIZE + 20  // version
-        + PlatformStatistics::SPACE; // statistics
+        + PlatformStatistics::SPACE // statistics
+        + U8_SIZE * 3  // reward ratios
+        + 1            // auto_settlement_action enum
+        + U64_SIZE     // total_transactions
+        + TransferLimitTiers::SPACE // transfer_limit_tiers
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE // pending_super_admin
+        + U8_SIZE      // approval_threshold
+        + U64_SIZE     // proposal_count
+        + U8_SIZE      // layout_version
+        + ReputationSettings::SPACE // reputation_settings
+        + InflationSettings::SPACE // inflation_settings
+        + MintEpoch::SPACE         // mint_epoch
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE; // platform_mint
+
+    /// 当前账户布局版本
+    pub const LAYOUT_VERSION: u8 = 2;
 
     /// 初始化平台配置
     pub fn initialize(
@@ -181,6 +445,9 @@ impl PlatformConfig {
     ) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
         
+        Self::validate_fee_rate(platform_fee_rate)?;
+        Self::validate_multipliers(MIN_MULTIPLIER, base_multiplier, MAX_MULTIPLIER)?;
+        
         self.super_admin = super_admin;
         self.admins = Vec::new();
         self.base_multiplier = base_multiplier;
@@ -197,12 +464,108 @@ impl PlatformConfig {
         self.updated_at = current_time;
         self.version = version;
         self.statistics = PlatformStatistics::default();
+        self.producer_reward_ratio = PRODUCER_REWARD_RATIO as u8;
+        self.worker_reward_ratio = WORKER_REWARD_RATIO as u8;
+        self.platform_reward_ratio = PLATFORM_REWARD_RATIO as u8;
+        self.auto_settlement_action = AutoSettlementAction::default();
+        self.total_transactions = 0;
+        self.transfer_limit_tiers = TransferLimitTiers::default();
+        self.pending_super_admin = None;
+        self.approval_threshold = DEFAULT_APPROVAL_THRESHOLD;
+        self.proposal_count = 0;
+        self.layout_version = Self::LAYOUT_VERSION;
+        self.reputation_settings = ReputationSettings::default();
+        self.inflation_settings = InflationSettings::default();
+        self.mint_epoch = MintEpoch::default();
+        self.platform_mint = None;
+        
+        Ok(())
+    }
+
+    /// 更新平台参数
+    pub fn update_parameters(&mut self, params: PlatformParameters) -> Result<()> {
+        let platform_fee_rate = params.platform_fee_rate.unwrap_or(self.platform_fee_rate);
+        let base_multiplier = params.base_multiplier.unwrap_or(self.base_multiplier);
+        let min_multiplier = params.min_multiplier.unwrap_or(self.min_multiplier);
+        let max_multiplier = params.max_multiplier.unwrap_or(self.max_multiplier);
+        let producer_reward_ratio = params.producer_reward_ratio.unwrap_or(self.producer_reward_ratio);
+        let worker_reward_ratio = params.worker_reward_ratio.unwrap_or(self.worker_reward_ratio);
+        let platform_reward_ratio = params.platform_reward_ratio.unwrap_or(self.platform_reward_ratio);
+        
+        Self::validate_fee_rate(platform_fee_rate)?;
+        Self::validate_multipliers(min_multiplier, base_multiplier, max_multiplier)?;
+        require!(
+            producer_reward_ratio as u32 + worker_reward_ratio as u32 == RATIO_BASE,
+            crate::errors::SoonShopError::InvalidWorkerShareRatio
+        );
+        require!(
+            platform_reward_ratio as u32 <= RATIO_BASE,
+            crate::errors::SoonShopError::InvalidPlatformConfig
+        );
         
+        self.platform_fee_rate = platform_fee_rate;
+        self.base_multiplier = base_multiplier;
+        self.min_multiplier = min_multiplier;
+        self.max_multiplier = max_multiplier;
+        self.producer_reward_ratio = producer_reward_ratio;
+        self.worker_reward_ratio = worker_reward_ratio;
+        self.platform_reward_ratio = platform_reward_ratio;
+        if let Some(action) = params.auto_settlement_action {
+            self.auto_settlement_action = action;
+        }
+        if let Some(tiers) = params.transfer_limit_tiers {
+            self.transfer_limit_tiers = tiers;
+        }
+        if let Some(settings) = params.reputation_settings {
+            settings.validate()?;
+            self.reputation_settings = settings;
+        }
+        if let Some(settings) = params.inflation_settings {
+            settings.validate()?;
+            self.inflation_settings = settings;
+        }
+        self.updated_at = Clock::get()?.unix_timestamp;
+        
+        Ok(())
+    }
+
+    /// 校验平台费率
+    fn validate_fee_rate(platform_fee_rate: u16) -> Result<()> {
+        require!(
+            platform_fee_rate <= MAX_PLATFORM_FEE_RATE,
+            crate::errors::SoonShopError::InvalidFeeRate
+        );
         Ok(())
     }
 
+    /// 校验倍增系数范围：MIN_MULTIPLIER <= 最小 <= 基础 <= 最大 <= MAX_MULTIPLIER
+    fn validate_multipliers(min_multiplier: u8, base_multiplier: u8, max_multiplier: u8) -> Result<()> {
+        require!(
+            MIN_MULTIPLIER <= min_multiplier
+                && min_multiplier <= base_multiplier
+                && base_multiplier <= max_multiplier
+                && max_multiplier <= MAX_MULTIPLIER,
+            crate::errors::SoonShopError::InvalidMultiplier
+        );
+        Ok(())
+    }
+
+    /// 确定生产者实际使用的倍增系数
+    /// 
+    /// 优先级：管理员覆盖 > 有效期内的企业评估 > 基础倍增系数，结果限制在最小/最大倍增系数之间
+    pub fn resolve_multiplier(&self, override_multiplier: Option<u8>, evaluated_multiplier: Option<u8>) -> u8 {
+        override_multiplier
+            .or(evaluated_multiplier)
+            .unwrap_or(self.base_multiplier)
+            .clamp(self.min_multiplier, self.max_multiplier)
+    }
+
     /// 添加管理员
     pub fn add_admin(&mut self, admin: Pubkey) -> Result<()> {
+        require!(
+            admin != self.super_admin,
+            crate::errors::SoonShopError::CannotAddSuperAdminAsAdmin
+        );
         require!(
             self.admins.len() < MAX_ADMINS,
             crate::errors::SoonShopError::TooManyAdmins
@@ -222,11 +585,82 @@ impl PlatformConfig {
         let index = self.admins.iter().position(|&x| x == admin)
             .ok_or(crate::errors::SoonShopError::AdminNotFound)?;
         
+        // 移除后剩余管理员（含超级管理员）仍需满足审批阈值
+        require!(
+            Self::required_approvals_for(self.admins.len(), self.approval_threshold) <= self.admins.len(),
+            crate::errors::SoonShopError::InvalidApprovalThreshold
+        );
+        
         self.admins.remove(index);
         self.updated_at = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
+    /// 提议转移超级管理员，传入None时取消待接受的转移
+    pub fn propose_super_admin(&mut self, new_super_admin: Option<Pubkey>) -> Result<()> {
+        self.pending_super_admin = new_super_admin;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// 接受超级管理员转移，返回原超级管理员
+    pub fn accept_super_admin(&mut self, new_super_admin: Pubkey) -> Result<Pubkey> {
+        let pending = self.pending_super_admin
+            .ok_or(crate::errors::SoonShopError::NoPendingSuperAdmin)?;
+        require!(
+            pending == new_super_admin,
+            crate::errors::SoonShopError::NotPendingSuperAdmin
+        );
+        
+        // 新超级管理员原为普通管理员时从管理员列表移除，避免重复计票
+        self.admins.retain(|admin| admin != &new_super_admin);
+        let previous = std::mem::replace(&mut self.super_admin, new_super_admin);
+        self.pending_super_admin = None;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        
+        Ok(previous)
+    }
+
+    /// 设置多签审批阈值（1 ≤ 阈值 ≤ 管理员总数含超级管理员，存在多名管理员时不低于MIN_MULTI_ADMIN_APPROVALS）
+    pub fn set_approval_threshold(&mut self, threshold: u8) -> Result<()> {
+        let total_admins = self.admins.len() + 1;
+        require!(
+            threshold >= 1
+                && (threshold as usize) <= total_admins
+                && Self::required_approvals_for(total_admins, threshold) == threshold as usize,
+            crate::errors::SoonShopError::InvalidApprovalThreshold
+        );
+        
+        self.approval_threshold = threshold;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// 执行多签提案所需的审批数量
+    pub fn required_approvals(&self) -> usize {
+        Self::required_approvals_for(self.admins.len() + 1, self.approval_threshold)
+    }
+
+    /// 按管理员总数（含超级管理员）计算所需审批数量：仅超级管理员一人时为1，
+    /// 存在多名管理员时取审批阈值且不低于MIN_MULTI_ADMIN_APPROVALS
+    fn required_approvals_for(total_admins: usize, threshold: u8) -> usize {
+        if total_admins > 1 {
+            threshold.max(MIN_MULTI_ADMIN_APPROVALS) as usize
+        } else {
+            1
+        }
+    }
+
+    /// 按平台费率（基点）计算手续费
+    pub fn calculate_fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.platform_fee_rate as u128)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?
+            / BASIS_POINTS_BASE as u128;
+        
+        Ok(fee as u64)
+    }
+
     /// 检查是否为超级管理员
     pub fn is_super_admin(&self, pubkey: &Pubkey) -> bool {
         &self.super_admin == pubkey
@@ -242,11 +676,58 @@ impl PlatformConfig {
         self.is_super_admin(pubkey) || self.is_admin(pubkey)
     }
 
+    /// 根据企业评估综合评分计算倍增系数
+    /// 
+    /// 未达及格线时取最小倍增系数，及格及以上在基础倍增系数与最大倍增系数之间线性插值
+    pub fn multiplier_for_score(&self, score: u8) -> u8 {
+        if score < EVALUATION_PASS_THRESHOLD {
+            return self.min_multiplier;
+        }
+        
+        let score = score.min(MAX_EVALUATION_SCORE);
+        let range = self.max_multiplier.saturating_sub(self.base_multiplier) as u32;
+        let bonus = range * (score - EVALUATION_PASS_THRESHOLD) as u32
+            / (MAX_EVALUATION_SCORE - EVALUATION_PASS_THRESHOLD) as u32;
+        
+        self.base_multiplier.saturating_add(bonus as u8)
+    }
+
+    /// 紧急暂停是否仍在生效（超过自动恢复时间后视为已恢复）
+    pub fn is_emergency_active(&self, current_time: i64) -> bool {
+        match self.emergency_pause_time {
+            Some(pause_time) if self.is_emergency_paused => {
+                current_time < pause_time + EMERGENCY_AUTO_RESUME_TIME
+            }
+            _ => false,
+        }
+    }
+
+    /// 检查平台是否可以执行业务操作
+    pub fn check_not_paused(&self) -> Result<()> {
+        self.check_not_paused_at(Clock::get()?.unix_timestamp)
+    }
+
+    /// 检查平台在指定时间是否可以执行业务操作
+    pub fn check_not_paused_at(&self, current_time: i64) -> Result<()> {
+        if self.is_emergency_active(current_time) {
+            return Err(crate::errors::SoonShopError::PlatformEmergencyPaused.into());
+        }
+        
+        match self.status {
+            PlatformStatus::Active | PlatformStatus::Emergency => Ok(()),
+            PlatformStatus::Paused => Err(crate::errors::SoonShopError::SystemPaused.into()),
+            _ => Err(crate::errors::SoonShopError::PlatformNotActive.into()),
+        }
+    }
+
     /// 紧急暂停
-    pub fn emergency_pause(&mut self) -> Result<()> {
-        let current_time = Clock::get()?.unix_timestamp;
+    pub fn emergency_pause(&mut self, current_time: i64) -> Result<()> {
         let current_day = current_time / SECONDS_PER_DAY;
         
+        if self.is_emergency_active(current_time) {
+            return Err(crate::errors::SoonShopError::AlreadyPaused.into());
+        }
+        
         // 重置每日计数器
         if current_day > self.last_emergency_reset_day {
             self.daily_emergency_pauses = 0;
@@ -268,11 +749,47 @@ impl PlatformConfig {
     }
 
     /// 恢复正常运行
-    pub fn emergency_resume(&mut self) -> Result<()> {
+    pub fn emergency_resume(&mut self, current_time: i64) -> Result<()> {
+        if !self.is_emergency_paused {
+            return Err(crate::errors::SoonShopError::NotPaused.into());
+        }
+        
         self.is_emergency_paused = false;
         self.emergency_pause_time = None;
         self.status = PlatformStatus::Active;
-        self.updated_at = Clock::get()?.unix_timestamp;
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 当前时间所在铸造周期的状态
+    pub fn current_mint_epoch(&self, current_time: i64) -> MintEpoch {
+        let mut mint_epoch = self.mint_epoch.clone();
+        mint_epoch.roll(current_time, self.inflation_settings.epoch_duration);
+        mint_epoch
+    }
+
+    /// 本周期剩余铸造预算
+    pub fn remaining_mint_budget(&self, current_time: i64) -> u64 {
+        let mint_epoch = self.current_mint_epoch(current_time);
+        mint_epoch
+            .budget(self.inflation_settings.base_epoch_budget, self.max_multiplier)
+            .saturating_sub(mint_epoch.minted)
+    }
+
+    /// 检查最大供应量和本周期铸造预算，通过后计入本周期铸造数量
+    pub fn record_mint(&mut self, amount: u64, current_supply: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.inflation_settings.check_supply(current_supply, amount)?;
+        require!(
+            amount <= self.remaining_mint_budget(current_time),
+            crate::errors::SoonShopError::InflationRateExceeded
+        );
+        
+        self.mint_epoch.roll(current_time, self.inflation_settings.epoch_duration);
+        self.mint_epoch.minted += amount;
+        self.updated_at = current_time;
         
         Ok(())
     }
@@ -302,9 +819,11 @@ impl PlatformConfig {
             stats.consumed_vouchers = stats.consumed_vouchers.saturating_add(consumed);
         }
         
-        // 更新金额统计
+        // 更新金额统计（消费额同时计入当前铸造周期）
         if let Some(amount) = stats_update.consumption_amount_delta {
             stats.total_consumption_amount = stats.total_consumption_amount.saturating_add(amount);
+            self.mint_epoch.roll(Clock::get()?.unix_timestamp, self.inflation_settings.epoch_duration);
+            self.mint_epoch.consumption_volume = self.mint_epoch.consumption_volume.saturating_add(amount);
         }
         if let Some(amount) = stats_update.rewards_distributed_delta {
             stats.total_rewards_distributed = stats.total_rewards_distributed.saturating_add(amount);
@@ -312,6 +831,9 @@ impl PlatformConfig {
         if let Some(amount) = stats_update.platform_revenue_delta {
             stats.total_platform_revenue = stats.total_platform_revenue.saturating_add(amount);
         }
+        if let Some(transactions) = stats_update.transactions_delta {
+            self.total_transactions = self.total_transactions.saturating_add(transactions);
+        }
         
         stats.last_stats_update = Clock::get()?.unix_timestamp;
         self.updated_at = stats.last_stats_update;
@@ -325,6 +847,242 @@ impl PlatformStatistics {
     pub const SPACE: usize = U64_SIZE * 13 + I64_SIZE;
 }
 
+// ================================
+// 管理员多签提案账户
+// ================================
+
+/**
+ * 管理员多签提案
+ * 
+ * 特权操作需先创建提案，获得达到平台审批阈值的管理员审批后，才能由管理员执行对应指令
+ */
+#[account]
+#[derive(Debug)]
+pub struct AdminProposal {
+    /// 提案编号
+    pub id: u64,
+    
+    /// 提案发起人
+    pub proposer: Pubkey,
+    
+    /// 提案操作
+    pub action: AdminAction,
+    
+    /// 已审批的管理员
+    pub approvals: Vec<Pubkey>,
+    
+    /// 创建时间
+    pub created_at: i64,
+    
+    /// 过期时间
+    pub expires_at: i64,
+    
+    /// 是否已执行
+    pub executed: bool,
+    
+    /// 执行时间
+    pub executed_at: Option<i64>,
+}
+
+/**
+ * 需要多签审批的特权操作
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum AdminAction {
+    /// 铸造代币
+    MintTokens { mint: Pubkey, recipient: Pubkey, amount: u64 },
+    /// 解除紧急暂停
+    EmergencyResume,
+    /// 提取平台金库
+    WithdrawTreasury { destination: Pubkey, amount: u64 },
+    /// 添加管理员
+    AddAdmin { admin: Pubkey },
+    /// 移除管理员
+    RemoveAdmin { admin: Pubkey },
+    /// 设置多签审批阈值
+    SetApprovalThreshold { threshold: u8 },
+    /// 更新平台参数（绑定参数内容哈希）
+    UpdatePlatformParameters { params_hash: [u8; HASH_SIZE] },
+    /// 创建平台代币
+    CreatePlatformToken { mint: Pubkey, vault: Pubkey, initial_supply: u64 },
+}
+
+impl AdminAction {
+    /// 最大变体（MintTokens、CreatePlatformToken）的序列化大小
+    pub const SPACE: usize = 1 + PUBKEY_SIZE * 2 + U64_SIZE;
+}
+
+impl AdminProposal {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + U64_SIZE         // id
+        + PUBKEY_SIZE      // proposer
+        + AdminAction::SPACE
+        + VEC_PREFIX_SIZE + (MAX_ADMINS + 1) * PUBKEY_SIZE // approvals
+        + I64_SIZE * 2     // created_at, expires_at
+        + BOOL_SIZE        // executed
+        + OPTION_FLAG_SIZE + I64_SIZE; // executed_at
+
+    /// 初始化提案，发起人自动计入审批
+    pub fn initialize(&mut self, id: u64, proposer: Pubkey, action: AdminAction) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.id = id;
+        self.proposer = proposer;
+        self.action = action;
+        self.approvals = vec![proposer];
+        self.created_at = current_time;
+        self.expires_at = current_time + ADMIN_PROPOSAL_VALIDITY;
+        self.executed = false;
+        self.executed_at = None;
+        
+        Ok(())
+    }
+
+    /// 审批提案
+    pub fn approve(&mut self, approver: Pubkey) -> Result<()> {
+        self.check_open(Clock::get()?.unix_timestamp)?;
+        require!(
+            !self.approvals.contains(&approver),
+            crate::errors::SoonShopError::ProposalAlreadyApproved
+        );
+        
+        self.approvals.push(approver);
+        Ok(())
+    }
+
+    /// 当前仍具有管理权限的审批数量（已被移除的管理员不计入）
+    pub fn valid_approvals(&self, platform_config: &PlatformConfig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| platform_config.has_admin_permission(approver))
+            .count()
+    }
+
+    /// 校验提案与待执行操作一致且审批数量达到阈值，并标记为已执行
+    pub fn execute(&mut self, action: &AdminAction, platform_config: &PlatformConfig) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.check_open(current_time)?;
+        require!(
+            &self.action == action,
+            crate::errors::SoonShopError::ProposalActionMismatch
+        );
+        require!(
+            self.valid_approvals(platform_config) >= platform_config.required_approvals(),
+            crate::errors::SoonShopError::InsufficientApprovals
+        );
+        
+        self.executed = true;
+        self.executed_at = Some(current_time);
+        Ok(())
+    }
+
+    fn check_open(&self, current_time: i64) -> Result<()> {
+        require!(!self.executed, crate::errors::SoonShopError::ProposalAlreadyExecuted);
+        require!(current_time <= self.expires_at, crate::errors::SoonShopError::ProposalExpired);
+        Ok(())
+    }
+}
+
+/**
+ * 平台参数更新结构
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct PlatformParameters {
+    pub platform_fee_rate: Option<u16>,
+    pub base_multiplier: Option<u8>,
+    pub min_multiplier: Option<u8>,
+    pub max_multiplier: Option<u8>,
+    pub producer_reward_ratio: Option<u8>,
+    pub worker_reward_ratio: Option<u8>,
+    pub platform_reward_ratio: Option<u8>,
+    pub auto_settlement_action: Option<AutoSettlementAction>,
+    pub transfer_limit_tiers: Option<TransferLimitTiers>,
+    pub reputation_settings: Option<ReputationSettings>,
+    pub inflation_settings: Option<InflationSettings>,
+}
+
+impl PlatformParameters {
+    /// 参数内容哈希，多签提案据此绑定待更新的参数
+    pub fn hash(&self) -> Result<[u8; HASH_SIZE]> {
+        let data = self.try_to_vec()?;
+        Ok(anchor_lang::solana_program::hash::hash(&data).to_bytes())
+    }
+}
+
+// ================================
+// 生产者倍增系数覆盖账户
+// ================================
+
+/**
+ * 生产者倍增系数覆盖
+ * 
+ * 管理员为特定生产者指定的倍增系数，优先于企业评估结果
+ */
+#[account]
+#[derive(Debug)]
+pub struct ProducerMultiplier {
+    /// 生产者公钥
+    pub producer: Pubkey,
+    
+    /// 覆盖倍增系数
+    pub multiplier: u8,
+    
+    /// 设置的管理员
+    pub set_by: Pubkey,
+    
+    /// 过期时间
+    pub expires_at: Option<i64>,
+    
+    /// 更新时间
+    pub updated_at: i64,
+}
+
+impl ProducerMultiplier {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE * 2  // producer, set_by
+        + U8_SIZE          // multiplier
+        + OPTION_FLAG_SIZE + I64_SIZE  // expires_at
+        + I64_SIZE;        // updated_at
+
+    /// 设置覆盖倍增系数
+    pub fn set(
+        &mut self,
+        producer: Pubkey,
+        multiplier: u8,
+        set_by: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require!(
+            (MIN_MULTIPLIER..=MAX_MULTIPLIER).contains(&multiplier),
+            crate::errors::SoonShopError::InvalidMultiplier
+        );
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > current_time, crate::errors::SoonShopError::InvalidTimestamp);
+        }
+        
+        self.producer = producer;
+        self.multiplier = multiplier;
+        self.set_by = set_by;
+        self.expires_at = expires_at;
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 获取当前有效的覆盖倍增系数（已过期时返回None）
+    pub fn effective_multiplier(&self, current_time: i64) -> Option<u8> {
+        match self.expires_at {
+            Some(expires_at) if current_time > expires_at => None,
+            _ => Some(self.multiplier),
+        }
+    }
+}
+
 /**
  * 统计信息更新结构
  */
@@ -342,4 +1100,224 @@ pub struct StatisticsUpdate {
     pub consumption_amount_delta: Option<u64>,
     pub rewards_distributed_delta: Option<u64>,
     pub platform_revenue_delta: Option<u64>,
-} 
\ No newline at end of file
+    pub transactions_delta: Option<u64>,
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH: i64 = DEFAULT_MINT_EPOCH_DURATION;
+
+    fn platform_config() -> PlatformConfig {
+        PlatformConfig {
+            super_admin: Pubkey::new_unique(),
+            admins: Vec::new(),
+            base_multiplier: 3,
+            max_multiplier: 6,
+            min_multiplier: 2,
+            platform_fee_rate: DEFAULT_PLATFORM_FEE_RATE,
+            reward_pool: Pubkey::new_unique(),
+            status: PlatformStatus::Active,
+            is_emergency_paused: false,
+            emergency_pause_time: None,
+            daily_emergency_pauses: 0,
+            last_emergency_reset_day: 0,
+            created_at: 0,
+            updated_at: 0,
+            version: String::new(),
+            statistics: PlatformStatistics::default(),
+            producer_reward_ratio: PRODUCER_REWARD_RATIO as u8,
+            worker_reward_ratio: WORKER_REWARD_RATIO as u8,
+            platform_reward_ratio: PLATFORM_REWARD_RATIO as u8,
+            auto_settlement_action: AutoSettlementAction::default(),
+            total_transactions: 0,
+            transfer_limit_tiers: TransferLimitTiers::default(),
+            pending_super_admin: None,
+            approval_threshold: 1,
+            proposal_count: 0,
+            layout_version: PlatformConfig::LAYOUT_VERSION,
+            reputation_settings: ReputationSettings::default(),
+            inflation_settings: InflationSettings::default(),
+            mint_epoch: MintEpoch::default(),
+            platform_mint: None,
+        }
+    }
+
+    fn mint_epoch(epoch: i64, consumption_volume: u64, minted: u64) -> MintEpoch {
+        MintEpoch {
+            epoch,
+            consumption_volume,
+            previous_consumption_volume: 0,
+            minted,
+        }
+    }
+
+    #[test]
+    fn roll_keeps_counters_within_same_epoch() {
+        let mut state = mint_epoch(3, 500, 200);
+
+        state.roll(EPOCH * 4 - 1, EPOCH);
+        assert_eq!((state.epoch, state.consumption_volume, state.minted), (3, 500, 200));
+    }
+
+    #[test]
+    fn roll_carries_volume_into_next_epoch() {
+        let mut state = mint_epoch(3, 500, 200);
+
+        state.roll(EPOCH * 4, EPOCH);
+        assert_eq!(state.epoch, 4);
+        assert_eq!(state.previous_consumption_volume, 500);
+        assert_eq!((state.consumption_volume, state.minted), (0, 0));
+    }
+
+    #[test]
+    fn roll_drops_volume_after_skipped_epochs() {
+        let mut state = mint_epoch(3, 500, 200);
+        state.previous_consumption_volume = 300;
+
+        state.roll(EPOCH * 6, EPOCH);
+        assert_eq!(state.epoch, 6);
+        assert_eq!(state.previous_consumption_volume, 0);
+        assert_eq!((state.consumption_volume, state.minted), (0, 0));
+    }
+
+    #[test]
+    fn budget_adds_multiplied_volume_to_base() {
+        let mut state = mint_epoch(0, 100, 0);
+        state.previous_consumption_volume = 50;
+
+        assert_eq!(state.budget(1_000, 5), 1_000 + 150 * 5);
+        assert_eq!(mint_epoch(0, u64::MAX, 0).budget(1, MAX_MULTIPLIER), u64::MAX);
+    }
+
+    #[test]
+    fn check_supply_enforces_max_supply() {
+        let settings = InflationSettings {
+            max_supply: 1_000,
+            ..InflationSettings::default()
+        };
+
+        assert!(settings.check_supply(900, 100).is_ok());
+        assert!(settings.check_supply(900, 101).is_err());
+        assert!(settings.check_supply(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn default_inflation_settings_constrain_minting() {
+        let settings = InflationSettings::default();
+
+        assert!(settings.base_epoch_budget < settings.max_supply);
+        assert!(settings.check_supply(0, settings.max_supply).is_ok());
+        assert!(settings.check_supply(settings.max_supply, 1).is_err());
+    }
+
+    #[test]
+    fn resolve_multiplier_prefers_override_then_evaluation_then_base() {
+        let config = platform_config();
+
+        assert_eq!(config.resolve_multiplier(Some(4), Some(5)), 4);
+        assert_eq!(config.resolve_multiplier(None, Some(5)), 5);
+        assert_eq!(config.resolve_multiplier(None, None), 3);
+    }
+
+    #[test]
+    fn resolve_multiplier_clamps_to_min_and_max() {
+        let config = platform_config();
+
+        assert_eq!(config.resolve_multiplier(Some(MAX_MULTIPLIER), None), 6);
+        assert_eq!(config.resolve_multiplier(None, Some(MIN_MULTIPLIER)), 2);
+        assert_eq!(config.resolve_multiplier(Some(0), Some(9)), 2);
+    }
+
+    #[test]
+    fn validate_multipliers_requires_ordered_range_within_bounds() {
+        assert!(PlatformConfig::validate_multipliers(MIN_MULTIPLIER, 2, MAX_MULTIPLIER).is_ok());
+        assert!(PlatformConfig::validate_multipliers(3, 3, 3).is_ok());
+        assert!(PlatformConfig::validate_multipliers(0, 2, 5).is_err());
+        assert!(PlatformConfig::validate_multipliers(1, 2, MAX_MULTIPLIER + 1).is_err());
+        assert!(PlatformConfig::validate_multipliers(3, 2, 5).is_err());
+        assert!(PlatformConfig::validate_multipliers(1, 6, 5).is_err());
+    }
+
+    #[test]
+    fn emergency_pause_blocks_operations_until_auto_resume() {
+        let mut config = platform_config();
+        assert!(config.check_not_paused_at(1_000).is_ok());
+
+        config.emergency_pause(1_000).unwrap();
+        assert!(config.check_not_paused_at(1_000).is_err());
+        assert!(config.check_not_paused_at(1_000 + EMERGENCY_AUTO_RESUME_TIME - 1).is_err());
+        assert!(config.check_not_paused_at(1_000 + EMERGENCY_AUTO_RESUME_TIME).is_ok());
+    }
+
+    #[test]
+    fn emergency_resume_reopens_operations() {
+        let mut config = platform_config();
+        assert!(config.emergency_resume(1_000).is_err());
+
+        config.emergency_pause(1_000).unwrap();
+        config.emergency_resume(2_000).unwrap();
+        assert!(config.check_not_paused_at(2_000).is_ok());
+        assert_eq!(config.status, PlatformStatus::Active);
+    }
+
+    #[test]
+    fn emergency_pause_limits_pauses_per_day() {
+        let mut config = platform_config();
+        let day = SECONDS_PER_DAY * 10;
+
+        for pause in 0..MAX_EMERGENCY_PAUSES_PER_DAY as i64 {
+            config.emergency_pause(day + pause).unwrap();
+            assert!(config.emergency_pause(day + pause).is_err());
+            config.emergency_resume(day + pause).unwrap();
+        }
+        assert!(config.emergency_pause(day + SECONDS_PER_DAY - 1).is_err());
+        config.emergency_pause(day + SECONDS_PER_DAY).unwrap();
+        assert_eq!(config.daily_emergency_pauses, 1);
+    }
+
+    #[test]
+    fn paused_or_inactive_platform_rejects_operations() {
+        let mut config = platform_config();
+
+        config.status = PlatformStatus::Paused;
+        assert!(config.check_not_paused_at(0).is_err());
+        config.status = PlatformStatus::Maintenance;
+        assert!(config.check_not_paused_at(0).is_err());
+    }
+
+    #[test]
+    fn calculate_fee_charges_basis_points() {
+        let mut config = platform_config();
+
+        assert_eq!(config.calculate_fee(10_000).unwrap(), 50);
+        config.platform_fee_rate = MAX_PLATFORM_FEE_RATE;
+        assert_eq!(config.calculate_fee(10_000).unwrap(), 500);
+        config.platform_fee_rate = 0;
+        assert_eq!(config.calculate_fee(10_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn calculate_fee_rounds_down() {
+        let config = platform_config();
+
+        assert_eq!(config.calculate_fee(199).unwrap(), 0);
+        assert_eq!(config.calculate_fee(200).unwrap(), 1);
+        assert_eq!(config.calculate_fee(399).unwrap(), 1);
+    }
+
+    #[test]
+    fn calculate_fee_handles_large_amounts() {
+        let mut config = platform_config();
+        config.platform_fee_rate = BASIS_POINTS_BASE;
+
+        assert_eq!(config.calculate_fee(u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn validate_fee_rate_caps_at_max_platform_fee_rate() {
+        assert!(PlatformConfig::validate_fee_rate(MAX_PLATFORM_FEE_RATE).is_ok());
+        assert!(PlatformConfig::validate_fee_rate(MAX_PLATFORM_FEE_RATE + 1).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+/**
+ * SoonShop核心智能合约价格监控状态模块
+ *
+ * 本模块定义了价格监控相关的状态结构体，包括：
+ * - 商品类别参考价格
+ * - 价格历史记录
+ */
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use super::voucher::ProductCategory;
+
+// ================================
+// 价格数据账户
+// ================================
+
+/**
+ * 商品类别价格数据
+ *
+ * 每个商品类别一个账户，由授权喂价方更新参考价格，历史价格以环形缓冲区保存
+ */
+#[account]
+#[derive(Debug)]
+pub struct PriceData {
+    /// 商品类别
+    pub category: ProductCategory,
+
+    /// 授权喂价方列表
+    pub feeders: Vec<Pubkey>,
+
+    /// 当前参考价格（精度为 PRICE_PRECISION）
+    pub current_price: u64,
+
+    /// 当前价格置信度（百分比）
+    pub confidence: u32,
+
+    /// 最后更新时间
+    pub last_updated: i64,
+
+    /// 最后更新的喂价方
+    pub last_feeder: Pubkey,
+
+    /// 历史价格（环形缓冲区）
+    pub history: Vec<PricePoint>,
+
+    /// 下一条历史记录写入位置
+    pub history_head: u16,
+
+    /// 创建时间
+    pub created_at: i64,
+}
+
+/**
+ * 历史价格点
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PricePoint {
+    /// 价格
+    pub price: u64,
+
+    /// 记录时间
+    pub timestamp: i64,
+}
+
+// ================================
+// 实现
+// ================================
+
+impl PriceData {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + 1                                            // category enum
+        + VEC_PREFIX_SIZE + MAX_PRICE_FEEDERS * PUBKEY_SIZE // feeders
+        + U64_SIZE                                     // current_price
+        + U32_SIZE                                     // confidence
+        + I64_SIZE                                     // last_updated
+        + PUBKEY_SIZE                                  // last_feeder
+        + VEC_PREFIX_SIZE + MAX_PRICE_HISTORY_RECORDS * PricePoint::SPACE // history
+        + U16_SIZE                                     // history_head
+        + I64_SIZE;                                    // created_at
+
+    /// 初始化价格数据
+    pub fn initialize(&mut self, category: ProductCategory, feeders: Vec<Pubkey>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.category = category;
+        self.set_feeders(feeders)?;
+        self.current_price = 0;
+        self.confidence = 0;
+        self.last_updated = 0;
+        self.last_feeder = Pubkey::default();
+        self.history = Vec::new();
+        self.history_head = 0;
+        self.created_at = current_time;
+
+        Ok(())
+    }
+
+    /// 设置授权喂价方
+    pub fn set_feeders(&mut self, feeders: Vec<Pubkey>) -> Result<()> {
+        require!(
+            feeders.len() <= MAX_PRICE_FEEDERS,
+            crate::errors::SoonShopError::ArrayTooLong
+        );
+
+        self.feeders = feeders;
+        Ok(())
+    }
+
+    /// 检查是否为授权喂价方
+    pub fn is_feeder(&self, pubkey: &Pubkey) -> bool {
+        self.feeders.contains(pubkey)
+    }
+
+    /// 更新参考价格
+    pub fn update_price(&mut self, feeder: Pubkey, price: u64, confidence: u32) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(price > 0, crate::errors::SoonShopError::InvalidPrice);
+        require!(
+            (MIN_CONFIDENCE_THRESHOLD..=100).contains(&confidence),
+            crate::errors::SoonShopError::InvalidPriceData
+        );
+        require!(
+            current_time >= self.last_updated + MIN_PRICE_UPDATE_INTERVAL,
+            crate::errors::SoonShopError::UserOperationTooFrequent
+        );
+
+        // 与历史均价偏离超过波动阈值的喂价视为异常
+        if let Some(average) = self.history_average() {
+            if Self::deviation_percent(price, average) > PRICE_VOLATILITY_THRESHOLD as u64 {
+                return Err(crate::errors::SoonShopError::AbnormalPriceVolatility.into());
+            }
+        }
+
+        self.record_price(feeder, price, confidence, current_time);
+
+        Ok(())
+    }
+
+    /// 管理员强制设置参考价格，清空价格历史后以新价格作为基准，用于真实价格发生剧烈变动时
+    pub fn force_update_price(&mut self, authority: Pubkey, price: u64, confidence: u32) -> Result<()> {
+        require!(price > 0, crate::errors::SoonShopError::InvalidPrice);
+        require!(
+            (MIN_CONFIDENCE_THRESHOLD..=100).contains(&confidence),
+            crate::errors::SoonShopError::InvalidPriceData
+        );
+
+        self.history.clear();
+        self.history_head = 0;
+        self.record_price(authority, price, confidence, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+
+    /// 写入价格历史并更新当前价格
+    fn record_price(&mut self, feeder: Pubkey, price: u64, confidence: u32, current_time: i64) {
+        let point = PricePoint {
+            price,
+            timestamp: current_time,
+        };
+        if self.history.len() < MAX_PRICE_HISTORY_RECORDS {
+            self.history.push(point);
+        } else {
+            self.history[self.history_head as usize] = point;
+        }
+        self.history_head = ((self.history_head as usize + 1) % MAX_PRICE_HISTORY_RECORDS) as u16;
+
+        self.current_price = price;
+        self.confidence = confidence;
+        self.last_updated = current_time;
+        self.last_feeder = feeder;
+    }
+
+    /// 获取未过时的参考价格
+    pub fn reference_price(&self, current_time: i64) -> Result<u64> {
+        if self.current_price == 0 || current_time > self.last_updated + MAX_PRICE_STALENESS {
+            return Err(crate::errors::SoonShopError::OutdatedPriceData.into());
+        }
+
+        Ok(self.current_price)
+    }
+
+    /// 校验商品单价与参考价格的偏离是否在波动阈值内
+    pub fn check_unit_price(&self, unit_price: u64, current_time: i64) -> Result<()> {
+        let reference_price = self.reference_price(current_time)?;
+
+        if Self::deviation_percent(unit_price, reference_price) > PRICE_VOLATILITY_THRESHOLD as u64 {
+            return Err(crate::errors::SoonShopError::SuspectedPriceManipulation.into());
+        }
+
+        Ok(())
+    }
+
+    /// 历史价格均值
+    fn history_average(&self) -> Option<u64> {
+        if self.history.is_empty() {
+            return None;
+        }
+
+        let total: u128 = self.history.iter().map(|point| point.price as u128).sum();
+        Some((total / self.history.len() as u128) as u64)
+    }
+
+    /// 计算价格相对参考价格的偏离百分比
+    fn deviation_percent(price: u64, reference_price: u64) -> u64 {
+        let difference = price.abs_diff(reference_price) as u128;
+        (difference * RATIO_BASE as u128 / reference_price.max(1) as u128) as u64
+    }
+}
+
+impl PricePoint {
+    pub const SPACE: usize = U64_SIZE + I64_SIZE;
+}
+
+impl ProductCategory {
+    /// 价格数据PDA种子
+    pub fn seed(&self) -> [u8; 1] {
+        [self.clone() as u8]
+    }
+}
@@ -0,0 +1,296 @@
+/**
+ * SoonShop核心智能合约用户账户状态模块
+ *
+ * 本模块定义了链上用户账户相关的状态结构体，包括：
+ * - 用户账户（用户类型、认证状态、信誉评分）
+ * - 管理员授予的角色
+ */
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::SoonShopError;
+use super::voucher::UserType;
+
+// ================================
+// 用户账户
+// ================================
+
+/**
+ * 用户账户
+ *
+ * 由用户自行注册，经管理员认证后才能被授予生产者、商家、评估员等角色；
+ * 发行、消费和评估指令据此校验参与方身份。信誉评分由商家给出的质量评分
+ * （生产者）和争议结果（商家）滚动更新
+ */
+#[account]
+#[derive(Debug)]
+pub struct UserAccount {
+    /// 用户公钥
+    pub user_id: Pubkey,
+
+    /// 用户类型
+    pub user_type: UserType,
+
+    /// 用户名
+    pub username: String,
+
+    /// 是否已通过认证
+    pub is_verified: bool,
+
+    /// 最近一次设置认证状态的管理员
+    pub verified_by: Option<Pubkey>,
+
+    /// 认证时间
+    pub verified_at: Option<i64>,
+
+    /// 已授予的角色
+    pub roles: UserRoles,
+
+    /// 注册时间
+    pub created_at: i64,
+
+    /// 更新时间
+    pub updated_at: i64,
+
+    /// 信誉评分（0-100）
+    pub reputation_score: u8,
+
+    /// 已计入信誉的质量评分次数
+    pub rated_count: u64,
+
+    /// 争议败诉次数
+    pub disputes_lost: u64,
+}
+
+/**
+ * 用户角色
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum UserRole {
+    /// 生产者（可发行提货券）
+    Producer,
+    /// 商家（可接受提货券消费）
+    Merchant,
+    /// 评估员（可提交企业评估）
+    Evaluator,
+}
+
+/**
+ * 已授予的角色集合
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UserRoles {
+    /// 生产者角色
+    pub producer: bool,
+
+    /// 商家角色
+    pub merchant: bool,
+
+    /// 评估员角色
+    pub evaluator: bool,
+}
+
+// ================================
+// 实现方法
+// ================================
+
+impl UserAccount {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE                                // user_id
+        + 1                                          // user_type enum
+        + STRING_PREFIX_SIZE + MAX_USERNAME_LENGTH   // username
+        + BOOL_SIZE                                  // is_verified
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE             // verified_by
+        + OPTION_FLAG_SIZE + I64_SIZE                // verified_at
+        + UserRoles::SPACE                           // roles
+        + I64_SIZE * 2                               // created_at, updated_at
+        + U8_SIZE                                    // reputation_score
+        + U64_SIZE * 2;                              // rated_count, disputes_lost
+
+    /// 初始化用户账户
+    pub fn initialize(&mut self, user: Pubkey, user_type: UserType, username: String) -> Result<()> {
+        if username.len() < MIN_USERNAME_LENGTH || username.len() > MAX_USERNAME_LENGTH {
+            return Err(SoonShopError::InvalidUsername.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.user_id = user;
+        self.user_type = user_type;
+        self.username = username;
+        self.is_verified = false;
+        self.verified_by = None;
+        self.verified_at = None;
+        self.roles = UserRoles::default();
+        self.created_at = current_time;
+        self.updated_at = current_time;
+        self.reputation_score = DEFAULT_REPUTATION_SCORE;
+        self.rated_count = 0;
+        self.disputes_lost = 0;
+
+        Ok(())
+    }
+
+    /// 设置认证状态，撤销认证后已授予的角色保留但不再生效
+    pub fn set_verified(&mut self, verified: bool, authority: Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.is_verified = verified;
+        self.verified_by = Some(authority);
+        self.verified_at = if verified { Some(current_time) } else { None };
+        self.updated_at = current_time;
+
+        Ok(())
+    }
+
+    /// 是否拥有指定角色
+    pub fn has_role(&self, role: &UserRole) -> bool {
+        match role {
+            UserRole::Producer => self.roles.producer,
+            UserRole::Merchant => self.roles.merchant,
+            UserRole::Evaluator => self.roles.evaluator,
+        }
+    }
+
+    /// 授予角色，只能授予已认证用户
+    pub fn grant_role(&mut self, role: &UserRole) -> Result<()> {
+        if !self.is_verified {
+            return Err(SoonShopError::UserNotVerified.into());
+        }
+        if self.has_role(role) {
+            return Err(SoonShopError::RoleAlreadyGranted.into());
+        }
+
+        self.set_role(role, true);
+        self.updated_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// 撤销角色
+    pub fn revoke_role(&mut self, role: &UserRole) -> Result<()> {
+        if !self.has_role(role) {
+            return Err(SoonShopError::RoleNotGranted.into());
+        }
+
+        self.set_role(role, false);
+        self.updated_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// 检查用户已认证且拥有指定角色
+    pub fn check_role(&self, role: &UserRole) -> Result<()> {
+        if !self.is_verified || !self.has_role(role) {
+            return Err(role.not_verified_error().into());
+        }
+        Ok(())
+    }
+
+    /// 计入一次质量评分（1-10），按最近REPUTATION_WINDOW次评分滚动平均
+    pub fn record_quality_score(&mut self, quality_score: u8, current_time: i64) {
+        let sample = (quality_score as u64 * MAX_REPUTATION_SCORE as u64 / 10)
+            .min(MAX_REPUTATION_SCORE as u64);
+        let window = (self.rated_count + 1).min(REPUTATION_WINDOW);
+        let score = (self.reputation_score as u64 * (window - 1) + sample) / window;
+
+        self.reputation_score = score as u8;
+        self.rated_count += 1;
+        self.updated_at = current_time;
+    }
+
+    /// 记录一次争议败诉并扣减信誉评分
+    pub fn record_dispute_lost(&mut self) -> Result<()> {
+        self.reputation_score = self.reputation_score.saturating_sub(DISPUTE_REPUTATION_PENALTY);
+        self.disputes_lost += 1;
+        self.updated_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// 检查信誉评分不低于指定门槛
+    pub fn check_reputation(&self, min_reputation: u8) -> Result<()> {
+        if self.reputation_score < min_reputation {
+            return Err(SoonShopError::UserReputationTooLow.into());
+        }
+        Ok(())
+    }
+
+    fn set_role(&mut self, role: &UserRole, granted: bool) {
+        match role {
+            UserRole::Producer => self.roles.producer = granted,
+            UserRole::Merchant => self.roles.merchant = granted,
+            UserRole::Evaluator => self.roles.evaluator = granted,
+        }
+    }
+}
+
+impl UserRole {
+    /// 角色未认证时返回的错误
+    pub fn not_verified_error(&self) -> SoonShopError {
+        match self {
+            UserRole::Producer => SoonShopError::ProducerNotVerified,
+            UserRole::Merchant => SoonShopError::MerchantNotVerified,
+            UserRole::Evaluator => SoonShopError::EvaluatorNotVerified,
+        }
+    }
+}
+
+impl UserRoles {
+    /// 计算结构所需空间
+    pub const SPACE: usize = BOOL_SIZE * 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_account(reputation_score: u8, rated_count: u64) -> UserAccount {
+        UserAccount {
+            user_id: Pubkey::new_unique(),
+            user_type: UserType::Regular,
+            username: "producer".to_string(),
+            is_verified: true,
+            verified_by: None,
+            verified_at: None,
+            roles: UserRoles::default(),
+            created_at: 0,
+            updated_at: 0,
+            reputation_score,
+            rated_count,
+            disputes_lost: 0,
+        }
+    }
+
+    #[test]
+    fn record_quality_score_averages_over_ratings_received() {
+        let mut account = user_account(DEFAULT_REPUTATION_SCORE, 0);
+
+        account.record_quality_score(4, 100);
+        assert_eq!((account.reputation_score, account.rated_count, account.updated_at), (40, 1, 100));
+
+        account.record_quality_score(10, 200);
+        assert_eq!((account.reputation_score, account.rated_count), (70, 2));
+
+        account.record_quality_score(7, 300);
+        assert_eq!(account.reputation_score, 70);
+    }
+
+    #[test]
+    fn record_quality_score_caps_window() {
+        let mut account = user_account(80, REPUTATION_WINDOW * 5);
+
+        account.record_quality_score(0, 0);
+        assert_eq!(account.reputation_score, (80 * (REPUTATION_WINDOW - 1) / REPUTATION_WINDOW) as u8);
+        assert_eq!(account.rated_count, REPUTATION_WINDOW * 5 + 1);
+    }
+
+    #[test]
+    fn record_quality_score_clamps_out_of_range_scores() {
+        let mut account = user_account(0, 0);
+
+        account.record_quality_score(u8::MAX, 0);
+        assert_eq!(account.reputation_score, MAX_REPUTATION_SCORE);
+    }
+}
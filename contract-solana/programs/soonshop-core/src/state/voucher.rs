@@ -9,6 +9,7 @@
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use super::platform::AutoSettlementAction;
 
 // ================================
 // 提货券账户
@@ -60,6 +61,18 @@ pub struct Voucher {
     
     /// 提货券统计
     pub statistics: VoucherStatistics,
+    
+    /// 上游提货券（原材料供应商），每个提货券只关联一个上游，多级供应商沿上游链逐层关联
+    pub upstream_voucher: Option<Pubkey>,
+    
+    /// 未结算的消费记录数
+    pub outstanding_consumptions: u64,
+    
+    /// 提货券PDA bump（用于托管账户签名）
+    pub bump: u8,
+    
+    /// 账户布局版本
+    pub layout_version: u8,
 }
 
 /**
@@ -212,9 +225,10 @@ pub struct VoucherConfig {
 /**
  * 用户类型枚举
  */
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Default)]
 pub enum UserType {
     /// 普通用户
+    #[default]
     Regular,
     /// VIP用户
     VIP,
@@ -266,6 +280,27 @@ pub struct ClaimRestrictions {
     pub claim_end_time: Option<i64>,
 }
 
+/**
+ * 获取限制设置（发行时由生产者指定，数量为0表示不限制）
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct ClaimRestrictionSettings {
+    /// 每人获取限制
+    pub per_user_limit: u64,
+    
+    /// 每日获取限制
+    pub daily_limit: u64,
+    
+    /// 每月获取限制
+    pub monthly_limit: u64,
+    
+    /// 获取开始时间
+    pub claim_start_time: Option<i64>,
+    
+    /// 获取结束时间
+    pub claim_end_time: Option<i64>,
+}
+
 /**
  * 提货券统计
  */
@@ -299,6 +334,47 @@ pub struct VoucherStatistics {
     pub last_stats_update: i64,
 }
 
+// ================================
+// 提货券领取记录账户
+// ================================
+
+/**
+ * 提货券领取记录
+ * 
+ * 每个（提货券，持有人）一个账户，记录该持有人在此提货券上的累计获取情况和可消费余额；
+ * 提货券允许转让时，余额可在持有人之间转移
+ */
+#[account]
+#[derive(Debug)]
+pub struct VoucherClaim {
+    /// 提货券账户
+    pub voucher: Pubkey,
+    
+    /// 消费者公钥
+    pub consumer: Pubkey,
+    
+    /// 累计获取额度
+    pub claimed_amount: u64,
+    
+    /// 获取次数
+    pub claim_count: u64,
+    
+    /// 首次获取时间
+    pub first_claimed_at: i64,
+    
+    /// 最近获取时间
+    pub last_claimed_at: i64,
+    
+    /// 可消费余额（已获取未消费的额度）
+    pub balance: u64,
+    
+    /// 累计转入额度
+    pub transferred_in: u64,
+    
+    /// 累计转出额度
+    pub transferred_out: u64,
+}
+
 // ================================
 // 消费记录账户
 // ================================
@@ -352,6 +428,48 @@ pub struct ConsumptionRecord {
     
     /// 奖励信息
     pub reward_info: RewardInfo,
+    
+    /// 争议信息
+    pub dispute: Option<DisputeInfo>,
+
+    /// 提货券账户地址
+    pub voucher: Pubkey,
+
+    /// 托管代币释放给商家的时间（争议期结束后释放）
+    pub escrow_released_at: Option<i64>,
+}
+
+/**
+ * 消费记录初始化参数
+ */
+#[derive(Clone, Debug)]
+pub struct ConsumptionParams {
+    /// 消费记录ID（提货券ID_消费序号）
+    pub id: String,
+    
+    /// 提货券ID
+    pub voucher_id: String,
+    
+    /// 提货券账户地址
+    pub voucher: Pubkey,
+    
+    /// 消费者公钥
+    pub consumer: Pubkey,
+    
+    /// 商家公钥
+    pub merchant: Pubkey,
+    
+    /// 消费金额
+    pub amount: u64,
+    
+    /// 消费数量
+    pub quantity: u64,
+    
+    /// 消费地点
+    pub location: String,
+    
+    /// 消费备注
+    pub notes: String,
 }
 
 /**
@@ -417,6 +535,41 @@ pub enum ProofType {
     Other,
 }
 
+/**
+ * 消费争议信息
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DisputeInfo {
+    /// 争议原因
+    pub reason: String,
+    
+    /// 发起时间
+    pub opened_at: i64,
+    
+    /// 发起争议前的消费状态
+    pub previous_status: ConsumptionStatus,
+    
+    /// 处理时间
+    pub resolved_at: Option<i64>,
+    
+    /// 仲裁人
+    pub arbiter: Option<Pubkey>,
+    
+    /// 处理结果
+    pub outcome: Option<DisputeOutcome>,
+}
+
+/**
+ * 争议处理结果枚举
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum DisputeOutcome {
+    /// 退回额度至提货券
+    RefundToVoucher,
+    /// 维持原消费
+    Uphold,
+}
+
 /**
  * 奖励信息
  */
@@ -494,7 +647,14 @@ impl Voucher {
         + I64_SIZE                  // updated_at
         + VoucherConfig::SPACE      // config
         + ClaimRestrictions::SPACE  // claim_restrictions
-        + VoucherStatistics::SPACE; // statistics
+        + VoucherStatistics::SPACE  // statistics
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE // upstream_voucher
+        + U64_SIZE                  // outstanding_consumptions
+        + U8_SIZE                   // bump
+        + U8_SIZE;                  // layout_version
+
+    /// 当前账户布局版本
+    pub const LAYOUT_VERSION: u8 = 2;
 
     /// 初始化提货券
     pub fn initialize(
@@ -532,12 +692,49 @@ impl Voucher {
             claim_end_time: expires_at,
         };
         self.statistics = VoucherStatistics::default();
+        self.upstream_voucher = None;
+        self.outstanding_consumptions = 0;
+        self.layout_version = Self::LAYOUT_VERSION;
+        
+        Ok(())
+    }
+
+    /// 关联上游提货券（每个提货券只能关联一个上游提货券）
+    pub fn link_upstream(&mut self, upstream_voucher: Pubkey, upstream_producer: &Pubkey, current_time: i64) -> Result<()> {
+        // 上游关联只能设置一次
+        if self.upstream_voucher.is_some() {
+            return Err(crate::errors::SoonShopError::InvalidUpstreamConsumption.into());
+        }
+        
+        // 不能以自身产品作为上游
+        if upstream_producer == &self.producer {
+            return Err(crate::errors::SoonShopError::InvalidUpstreamConsumption.into());
+        }
+        
+        self.upstream_voucher = Some(upstream_voucher);
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 设置获取限制
+    pub fn set_claim_restrictions(&mut self, settings: ClaimRestrictionSettings) -> Result<()> {
+        if let (Some(start), Some(end)) = (settings.claim_start_time, settings.claim_end_time) {
+            require!(start < end, crate::errors::SoonShopError::InvalidVoucherConfig);
+        }
+        
+        // 未指定结束时间时以过期时间为准
+        self.claim_restrictions.per_user_limit = settings.per_user_limit;
+        self.claim_restrictions.daily_limit = settings.daily_limit;
+        self.claim_restrictions.monthly_limit = settings.monthly_limit;
+        self.claim_restrictions.claim_start_time = settings.claim_start_time;
+        self.claim_restrictions.claim_end_time = settings.claim_end_time.or(self.expires_at);
         
         Ok(())
     }
 
     /// 获取提货券额度
-    pub fn claim_credits(&mut self, amount: u64) -> Result<()> {
+    pub fn claim_credits(&mut self, amount: u64, user_type: &UserType, user_claimed: u64) -> Result<()> {
         // 检查状态
         if self.status != VoucherStatus::Active {
             return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
@@ -556,22 +753,70 @@ impl Voucher {
             return Err(crate::errors::SoonShopError::InsufficientVoucherQuantity.into());
         }
         
+        let current_time = Clock::get()?.unix_timestamp;
+        self.check_claim_config(amount, user_type)?;
+        self.claim_restrictions.reset_counters(current_time);
+        self.claim_restrictions.check(amount, user_claimed, current_time)?;
+        
         // 更新获取额度
         self.claimed_credits += amount;
         self.claim_restrictions.current_claimed += amount;
+        self.claim_restrictions.today_claimed += amount;
+        self.claim_restrictions.monthly_claimed += amount;
         self.statistics.claim_count += 1;
-        self.updated_at = Clock::get()?.unix_timestamp;
+        if user_claimed == 0 {
+            self.statistics.unique_claimers += 1;
+        }
+        self.statistics.avg_claim_amount = self.claim_restrictions.current_claimed / self.statistics.claim_count;
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 检查提货券配置（获取数量与用户类型）
+    fn check_claim_config(&self, amount: u64, user_type: &UserType) -> Result<()> {
+        let config = &self.config;
+        
+        if amount < config.min_claim_amount {
+            return Err(crate::errors::SoonShopError::ClaimConditionNotMet.into());
+        }
+        if amount > config.max_claim_amount {
+            return Err(crate::errors::SoonShopError::ExceedsClaimLimit.into());
+        }
+        if !config.allow_partial_claim && amount != config.max_claim_amount {
+            return Err(crate::errors::SoonShopError::ClaimConditionNotMet.into());
+        }
+        if !config.allowed_user_types.is_empty() && !config.allowed_user_types.contains(user_type) {
+            return Err(crate::errors::SoonShopError::UserTypeRestriction.into());
+        }
+        
+        Ok(())
+    }
+
+    /// 检查已获取额度是否可以转让（取消后已获取的额度仍可转让）
+    pub fn check_transferable(&self) -> Result<()> {
+        if !self.config.allow_transfer {
+            return Err(crate::errors::SoonShopError::VoucherNotTransferable.into());
+        }
+        if self.status != VoucherStatus::Active && self.status != VoucherStatus::Cancelled {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
         
         Ok(())
     }
 
     /// 消费提货券额度
-    pub fn consume_credits(&mut self, amount: u64) -> Result<()> {
-        // 检查状态
-        if self.status != VoucherStatus::Active {
+    pub fn consume_credits(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        // 检查状态（取消后已获取的额度仍可消费）
+        if self.status != VoucherStatus::Active && self.status != VoucherStatus::Cancelled {
             return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
         }
         
+        // 检查过期时间
+        if self.is_past_expiry(current_time) {
+            return Err(crate::errors::SoonShopError::VoucherExpired.into());
+        }
+        
         // 检查已获取额度是否足够
         if self.consumed_credits + amount > self.claimed_credits {
             return Err(crate::errors::SoonShopError::InsufficientVoucherQuantity.into());
@@ -579,8 +824,14 @@ impl Voucher {
         
         // 更新消费额度
         self.consumed_credits += amount;
+        self.outstanding_consumptions += 1;
         self.statistics.consumption_count += 1;
-        self.updated_at = Clock::get()?.unix_timestamp;
+        self.updated_at = current_time;
+        
+        // 全部额度消费完毕后结束
+        if self.status == VoucherStatus::Active && self.consumed_credits == self.total_credits {
+            self.status = VoucherStatus::Ended;
+        }
         
         Ok(())
     }
@@ -607,6 +858,137 @@ impl Voucher {
         true
     }
 
+    /// 退回已消费额度（消费被取消时）
+    pub fn refund_consumption(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.consumed_credits = self.consumed_credits
+            .checked_sub(amount)
+            .ok_or(crate::errors::SoonShopError::MathUnderflow)?;
+        self.outstanding_consumptions = self.outstanding_consumptions.saturating_sub(1);
+        self.updated_at = current_time;
+        
+        // 已结束且未过期的提货券重新有可消费额度（已取消、已过期的提货券保持原状态）
+        if self.status == VoucherStatus::Ended && !self.is_past_expiry(current_time) {
+            self.status = VoucherStatus::Active;
+        }
+        
+        Ok(())
+    }
+
+    /// 计入商家核销时给出的质量评分，按最近REPUTATION_WINDOW次评分滚动平均
+    pub fn record_quality_score(&mut self, quality_score: u8) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let stats = &mut self.statistics;
+        
+        stats.avg_quality_score = if stats.avg_quality_score == 0.0 {
+            quality_score as f64
+        } else {
+            stats.avg_quality_score + (quality_score as f64 - stats.avg_quality_score) / REPUTATION_WINDOW as f64
+        };
+        stats.last_stats_update = current_time;
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 消费记录完成结算
+    pub fn settle_consumption(&mut self) -> Result<()> {
+        self.outstanding_consumptions = self.outstanding_consumptions
+            .checked_sub(1)
+            .ok_or(crate::errors::SoonShopError::MathUnderflow)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        
+        Ok(())
+    }
+
+    /// 暂停提货券
+    pub fn pause(&mut self) -> Result<()> {
+        if self.status != VoucherStatus::Active {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
+        
+        self.status = VoucherStatus::Paused;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        
+        Ok(())
+    }
+
+    /// 恢复提货券
+    pub fn resume(&mut self) -> Result<()> {
+        if self.status != VoucherStatus::Paused {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.is_past_expiry(current_time) {
+            return Err(crate::errors::SoonShopError::VoucherExpired.into());
+        }
+        
+        self.status = VoucherStatus::Active;
+        self.updated_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 取消提货券，退回未获取的额度，返回退回数量
+    pub fn cancel(&mut self) -> Result<u64> {
+        if self.status != VoucherStatus::Active && self.status != VoucherStatus::Paused {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
+        
+        let refunded = self.release_unclaimed();
+        self.status = VoucherStatus::Cancelled;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        
+        Ok(refunded)
+    }
+
+    /// 过期提货券，退回未获取的额度，返回退回数量
+    pub fn expire(&mut self) -> Result<u64> {
+        if self.status != VoucherStatus::Active && self.status != VoucherStatus::Paused {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        if !self.is_past_expiry(current_time) {
+            return Err(crate::errors::SoonShopError::InvalidTimestamp.into());
+        }
+        
+        let refunded = self.release_unclaimed();
+        self.status = VoucherStatus::Expired;
+        self.updated_at = current_time;
+        
+        Ok(refunded)
+    }
+
+    /// 检查提货券是否已完全结算，可以关闭
+    pub fn check_closable(&self) -> Result<()> {
+        let settled_status = match self.status {
+            // 已过期提货券中已获取未消费的额度作废
+            VoucherStatus::Expired => true,
+            VoucherStatus::Cancelled | VoucherStatus::Ended => self.consumable_credits() == 0,
+            _ => false,
+        };
+        
+        if !settled_status || self.outstanding_consumptions > 0 {
+            return Err(crate::errors::SoonShopError::InvalidVoucherStatus.into());
+        }
+        
+        Ok(())
+    }
+
+    /// 是否已超过过期时间
+    fn is_past_expiry(&self, current_time: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if current_time > expires_at)
+    }
+
+    /// 将总额度收缩至已获取额度，返回释放的未获取额度
+    fn release_unclaimed(&mut self) -> u64 {
+        let unclaimed = self.available_credits();
+        self.total_credits = self.claimed_credits;
+        self.claim_restrictions.total_limit = self.claimed_credits;
+        unclaimed
+    }
+
     /// 获取可用额度
     pub fn available_credits(&self) -> u64 {
         self.total_credits - self.claimed_credits
@@ -621,7 +1003,7 @@ impl Voucher {
 impl ConsumptionRecord {
     /// 计算账户所需空间
     pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
-        + STRING_PREFIX_SIZE + 50    // id
+        + STRING_PREFIX_SIZE + 72    // id（提货券ID_消费序号）
         + STRING_PREFIX_SIZE + 50    // voucher_id
         + PUBKEY_SIZE * 2           // consumer, merchant
         + U64_SIZE * 2              // amount, quantity
@@ -632,34 +1014,28 @@ impl ConsumptionRecord {
         + STRING_PREFIX_SIZE + 200  // location
         + STRING_PREFIX_SIZE + 500  // notes
         + ConsumptionProof::SPACE   // proof
-        + RewardInfo::SPACE;        // reward_info
+        + RewardInfo::SPACE         // reward_info
+        + OPTION_FLAG_SIZE + DisputeInfo::SPACE // dispute
+        + PUBKEY_SIZE               // voucher
+        + OPTION_FLAG_SIZE + I64_SIZE; // escrow_released_at
 
     /// 初始化消费记录
-    pub fn initialize(
-        &mut self,
-        id: String,
-        voucher_id: String,
-        consumer: Pubkey,
-        merchant: Pubkey,
-        amount: u64,
-        quantity: u64,
-        location: String,
-        notes: String,
-    ) -> Result<()> {
+    pub fn initialize(&mut self, params: ConsumptionParams) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
         
-        self.id = id;
-        self.voucher_id = voucher_id;
-        self.consumer = consumer;
-        self.merchant = merchant;
-        self.amount = amount;
-        self.quantity = quantity;
+        self.id = params.id;
+        self.voucher_id = params.voucher_id;
+        self.voucher = params.voucher;
+        self.consumer = params.consumer;
+        self.merchant = params.merchant;
+        self.amount = params.amount;
+        self.quantity = params.quantity;
         self.status = ConsumptionStatus::Pending;
         self.consumed_at = current_time;
         self.confirmed_at = None;
         self.quality_score = None;
-        self.location = location;
-        self.notes = notes;
+        self.location = params.location;
+        self.notes = params.notes;
         self.proof = ConsumptionProof {
             proof_type: ProofType::Timestamp,
             proof_data: current_time.to_string(),
@@ -676,12 +1052,17 @@ impl ConsumptionRecord {
             reward_distributed_at: None,
             reward_recipients: Vec::new(),
         };
+        self.dispute = None;
+        self.escrow_released_at = None;
         
         Ok(())
     }
 
     /// 确认消费
-    pub fn confirm(&mut self, quality_score: u8) -> Result<()> {
+    pub fn confirm(&mut self, quality_score: u8, current_time: i64) -> Result<()> {
+        if self.status == ConsumptionStatus::Disputed {
+            return Err(crate::errors::SoonShopError::ConsumptionDisputed.into());
+        }
         if self.status != ConsumptionStatus::Pending {
             return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
         }
@@ -691,21 +1072,152 @@ impl ConsumptionRecord {
         }
         
         self.status = ConsumptionStatus::Confirmed;
-        self.confirmed_at = Some(Clock::get()?.unix_timestamp);
+        self.confirmed_at = Some(current_time);
         self.quality_score = Some(quality_score);
         
         Ok(())
     }
 
-    /// 完成消费
+    /// 记录已通过Ed25519预编译验证的商家签名证明
+    pub fn attach_signature_proof(
+        &mut self,
+        proof_hash: &[u8; HASH_SIZE],
+        signature: &[u8; SIGNATURE_SIZE],
+    ) -> Result<()> {
+        if self.proof.verified {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionProof.into());
+        }
+        
+        self.proof = ConsumptionProof {
+            proof_type: ProofType::Signature,
+            proof_data: to_hex(proof_hash),
+            proof_time: Clock::get()?.unix_timestamp,
+            proof_signature: to_hex(signature),
+            verified: true,
+        };
+        
+        Ok(())
+    }
+
+    /// 完成消费（托管代币释放后）
     pub fn complete(&mut self) -> Result<()> {
-        if self.status != ConsumptionStatus::Confirmed {
+        if self.status != ConsumptionStatus::Confirmed || self.escrow_released_at.is_none() {
             return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
         }
         
         self.status = ConsumptionStatus::Completed;
         Ok(())
     }
+
+    /// 争议截止时间：待确认的消费自消费时起算，已确认的消费自确认时起算
+    pub fn dispute_deadline(&self) -> i64 {
+        self.confirmed_at.unwrap_or(self.consumed_at) + CONSUMPTION_CONFIRMATION_TIMEOUT
+    }
+
+    /// 托管代币是否已释放给商家
+    pub fn escrow_released(&self) -> bool {
+        self.escrow_released_at.is_some()
+    }
+
+    /// 争议期结束后标记已确认消费的托管代币为已释放
+    pub fn release_escrow(&mut self, current_time: i64) -> Result<()> {
+        if self.status == ConsumptionStatus::Disputed {
+            return Err(crate::errors::SoonShopError::ConsumptionDisputed.into());
+        }
+        if self.status != ConsumptionStatus::Confirmed || self.escrow_released() {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
+        }
+        if current_time <= self.dispute_deadline() {
+            return Err(crate::errors::SoonShopError::ConsumptionDisputeWindowOpen.into());
+        }
+        
+        self.escrow_released_at = Some(current_time);
+        Ok(())
+    }
+
+    /// 自动结算：超时未确认的消费按配置自动确认或取消
+    pub fn auto_settle(&mut self, action: &AutoSettlementAction, current_time: i64) -> Result<()> {
+        if self.status != ConsumptionStatus::Pending {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
+        }
+        
+        if current_time < self.consumed_at + CONSUMPTION_CONFIRMATION_TIMEOUT + AUTO_SETTLEMENT_DELAY {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionTime.into());
+        }
+        
+        match action {
+            AutoSettlementAction::Confirm => self.confirm(DEFAULT_AUTO_SETTLEMENT_QUALITY_SCORE, current_time),
+            AutoSettlementAction::Cancel => {
+                self.status = ConsumptionStatus::Cancelled;
+                Ok(())
+            }
+        }
+    }
+
+    /// 发起消费争议，待确认和已确认的消费在争议截止时间前、托管代币释放前均可提出争议
+    pub fn open_dispute(&mut self, reason: String, current_time: i64) -> Result<()> {
+        if self.status == ConsumptionStatus::Disputed || self.dispute.is_some() {
+            return Err(crate::errors::SoonShopError::ConsumptionDisputed.into());
+        }
+        if (self.status != ConsumptionStatus::Pending && self.status != ConsumptionStatus::Confirmed)
+            || self.escrow_released()
+        {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
+        }
+        if current_time > self.dispute_deadline() {
+            return Err(crate::errors::SoonShopError::OperationTimeout.into());
+        }
+        require!(reason.len() <= 200, crate::errors::SoonShopError::StringTooLong);
+        
+        self.dispute = Some(DisputeInfo {
+            reason,
+            opened_at: current_time,
+            previous_status: self.status.clone(),
+            resolved_at: None,
+            arbiter: None,
+            outcome: None,
+        });
+        self.status = ConsumptionStatus::Disputed;
+        
+        Ok(())
+    }
+
+    /// 处理消费争议
+    pub fn resolve_dispute(&mut self, arbiter: Pubkey, outcome: DisputeOutcome, current_time: i64) -> Result<()> {
+        if self.status != ConsumptionStatus::Disputed {
+            return Err(crate::errors::SoonShopError::InvalidConsumptionStatus.into());
+        }
+        
+        let dispute = self.dispute.as_mut()
+            .ok_or(crate::errors::SoonShopError::InvalidConsumptionStatus)?;
+        
+        self.status = match outcome {
+            DisputeOutcome::RefundToVoucher => ConsumptionStatus::Cancelled,
+            DisputeOutcome::Uphold => dispute.previous_status.clone(),
+        };
+        dispute.resolved_at = Some(current_time);
+        dispute.arbiter = Some(arbiter);
+        dispute.outcome = Some(outcome);
+        
+        Ok(())
+    }
+
+    /// 争议是否已超过处理时限
+    pub fn dispute_timed_out(&self, current_time: i64) -> bool {
+        match &self.dispute {
+            Some(dispute) if self.status == ConsumptionStatus::Disputed => {
+                current_time > dispute.opened_at + DISPUTE_RESOLUTION_TIMEOUT
+            }
+            _ => false,
+        }
+    }
+}
+
+impl UserType {
+    /// 是否为消费者类型（企业和政府用户不计入消费者）
+    pub fn is_consumer(&self) -> bool {
+        !matches!(self, UserType::Enterprise | UserType::Government)
+    }
 }
 
 impl ProductInfo {
@@ -728,10 +1240,148 @@ impl VoucherConfig {
         + U64_SIZE * 4                         // amounts and fees
         + VEC_PREFIX_SIZE + 10 * 1            // allowed_user_types
         + VEC_PREFIX_SIZE + 10 * (STRING_PREFIX_SIZE + 50); // geographic_restrictions
+
+    /// 校验提货券配置
+    pub fn validate(&self, total_credits: u64) -> Result<()> {
+        require!(
+            self.min_claim_amount > 0
+                && self.min_claim_amount <= self.max_claim_amount
+                && self.max_claim_amount <= total_credits,
+            crate::errors::SoonShopError::InvalidVoucherConfig
+        );
+        require!(
+            self.allowed_user_types.len() <= 10 && self.geographic_restrictions.len() <= 10,
+            crate::errors::SoonShopError::InvalidVoucherConfig
+        );
+        require!(
+            self.geographic_restrictions.iter().all(|region| region.len() <= 50),
+            crate::errors::SoonShopError::InvalidVoucherConfig
+        );
+        
+        Ok(())
+    }
+}
+
+impl VoucherClaim {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE * 2  // voucher, consumer
+        + U64_SIZE * 2     // claimed_amount, claim_count
+        + I64_SIZE * 2     // first_claimed_at, last_claimed_at
+        + U64_SIZE         // balance
+        + U64_SIZE * 2;    // transferred_in, transferred_out
+
+    /// 是否为刚创建、尚未获取或转入过额度的账户
+    pub fn is_new(&self) -> bool {
+        self.claim_count == 0 && self.transferred_in == 0
+    }
+
+    /// 记录一次获取
+    pub fn record_claim(&mut self, voucher: Pubkey, consumer: Pubkey, amount: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        if self.claim_count == 0 {
+            self.voucher = voucher;
+            self.consumer = consumer;
+            self.first_claimed_at = current_time;
+        }
+        self.claimed_amount = self.claimed_amount
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        self.claim_count += 1;
+        self.last_claimed_at = current_time;
+        
+        Ok(())
+    }
+
+    /// 扣减可消费余额
+    pub fn consume(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_sub(amount)
+            .ok_or(crate::errors::SoonShopError::InsufficientVoucherQuantity)?;
+        
+        Ok(())
+    }
+
+    /// 退回可消费余额（消费被取消时）
+    pub fn refund(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        
+        Ok(())
+    }
+
+    /// 转出可消费余额
+    pub fn transfer_out(&mut self, amount: u64) -> Result<()> {
+        self.consume(amount)?;
+        self.transferred_out = self.transferred_out
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        
+        Ok(())
+    }
+
+    /// 转入可消费余额（不计入持有人的获取额度和获取次数）
+    pub fn receive_transfer(&mut self, voucher: Pubkey, holder: Pubkey, amount: u64) -> Result<()> {
+        if self.claim_count == 0 {
+            self.voucher = voucher;
+            self.consumer = holder;
+        }
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        self.transferred_in = self.transferred_in
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        
+        Ok(())
+    }
 }
 
 impl ClaimRestrictions {
     pub const SPACE: usize = U64_SIZE * 7 + I64_SIZE * 3 + OPTION_FLAG_SIZE * 2;
+
+    /// 跨日、跨月时重置获取计数
+    pub fn reset_counters(&mut self, current_time: i64) {
+        let current_day = current_time / SECONDS_PER_DAY;
+        
+        if current_day != self.last_reset_date {
+            self.today_claimed = 0;
+            if current_time / SECONDS_PER_MONTH != self.last_reset_date * SECONDS_PER_DAY / SECONDS_PER_MONTH {
+                self.monthly_claimed = 0;
+            }
+            self.last_reset_date = current_day;
+        }
+    }
+
+    /// 检查获取限制（时间窗口、每人、每日、每月、总量）
+    pub fn check(&self, amount: u64, user_claimed: u64, current_time: i64) -> Result<()> {
+        if let Some(start) = self.claim_start_time {
+            if current_time < start {
+                return Err(crate::errors::SoonShopError::TimeRestriction.into());
+            }
+        }
+        if let Some(end) = self.claim_end_time {
+            if current_time > end {
+                return Err(crate::errors::SoonShopError::TimeRestriction.into());
+            }
+        }
+        
+        let exceeds = |limit: u64, claimed: u64| limit > 0 && claimed.saturating_add(amount) > limit;
+        if exceeds(self.per_user_limit, user_claimed)
+            || exceeds(self.daily_limit, self.today_claimed)
+            || exceeds(self.monthly_limit, self.monthly_claimed)
+            || exceeds(self.total_limit, self.current_claimed)
+        {
+            return Err(crate::errors::SoonShopError::ExceedsClaimLimit.into());
+        }
+        
+        Ok(())
+    }
 }
 
 impl VoucherStatistics {
@@ -739,13 +1389,411 @@ impl VoucherStatistics {
 }
 
 impl ConsumptionProof {
-    pub const SPACE: usize = 1 + STRING_PREFIX_SIZE + 500 + I64_SIZE + STRING_PREFIX_SIZE + 100 + BOOL_SIZE;
+    pub const SPACE: usize = 1 + STRING_PREFIX_SIZE + 500 + I64_SIZE + STRING_PREFIX_SIZE + SIGNATURE_SIZE * 2 + BOOL_SIZE;
+
+    /// 商家（POS终端）签名的消息：消费记录地址 + 证明哈希
+    pub fn signed_message(consumption_record: &Pubkey, proof_hash: &[u8; HASH_SIZE]) -> Vec<u8> {
+        let mut message = Vec::with_capacity(PUBKEY_SIZE + HASH_SIZE);
+        message.extend_from_slice(consumption_record.as_ref());
+        message.extend_from_slice(proof_hash);
+        message
+    }
+}
+
+/// 将字节编码为小写十六进制字符串
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl DisputeInfo {
+    pub const SPACE: usize = STRING_PREFIX_SIZE + 200 // reason
+        + I64_SIZE                                   // opened_at
+        + 1                                          // previous_status enum
+        + OPTION_FLAG_SIZE + I64_SIZE                // resolved_at
+        + OPTION_FLAG_SIZE + PUBKEY_SIZE             // arbiter
+        + OPTION_FLAG_SIZE + 1;                      // outcome enum
 }
 
 impl RewardInfo {
-    pub const SPACE: usize = U64_SIZE * 4 + 1 + OPTION_FLAG_SIZE + I64_SIZE + VEC_PREFIX_SIZE + 10 * RewardRecipient::SPACE;
+    pub const SPACE: usize = U64_SIZE * 4 + 1 + OPTION_FLAG_SIZE + I64_SIZE + VEC_PREFIX_SIZE + MAX_REWARD_RECIPIENTS * RewardRecipient::SPACE;
 }
 
 impl RewardRecipient {
     pub const SPACE: usize = PUBKEY_SIZE + U64_SIZE + STRING_PREFIX_SIZE + 50 + 1;
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restrictions() -> ClaimRestrictions {
+        ClaimRestrictions {
+            per_user_limit: 0,
+            daily_limit: 0,
+            monthly_limit: 0,
+            total_limit: 0,
+            current_claimed: 0,
+            today_claimed: 0,
+            monthly_claimed: 0,
+            last_reset_date: 0,
+            claim_start_time: None,
+            claim_end_time: None,
+        }
+    }
+
+    fn voucher_claim(balance: u64) -> VoucherClaim {
+        VoucherClaim {
+            voucher: Pubkey::new_unique(),
+            consumer: Pubkey::new_unique(),
+            claimed_amount: balance,
+            claim_count: 1,
+            first_claimed_at: 0,
+            last_claimed_at: 0,
+            balance,
+            transferred_in: 0,
+            transferred_out: 0,
+        }
+    }
+
+    fn voucher(expires_at: Option<i64>) -> Voucher {
+        Voucher {
+            id: "voucher".to_string(),
+            producer: Pubkey::new_unique(),
+            product_info: ProductInfo {
+                name: String::new(),
+                description: String::new(),
+                category: ProductCategory::Food,
+                specifications: String::new(),
+                unit_price: 1,
+                unit: String::new(),
+                image_url: String::new(),
+                production_location: String::new(),
+                production_date: None,
+                shelf_life_days: None,
+                quality_grade: QualityGrade::Standard,
+                certifications: Vec::new(),
+            },
+            total_credits: 100,
+            claimed_credits: 100,
+            consumed_credits: 0,
+            status: VoucherStatus::Active,
+            created_at: 0,
+            expires_at,
+            updated_at: 0,
+            config: VoucherConfig {
+                allow_partial_claim: true,
+                allow_transfer: false,
+                require_appointment: false,
+                min_claim_amount: 1,
+                max_claim_amount: 100,
+                claim_fee: 0,
+                cancellation_fee: 0,
+                require_identity_verification: false,
+                allowed_user_types: Vec::new(),
+                geographic_restrictions: Vec::new(),
+            },
+            claim_restrictions: restrictions(),
+            statistics: VoucherStatistics::default(),
+            upstream_voucher: None,
+            outstanding_consumptions: 0,
+            bump: 0,
+            layout_version: Voucher::LAYOUT_VERSION,
+        }
+    }
+
+    fn consumption_record(consumed_at: i64) -> ConsumptionRecord {
+        ConsumptionRecord {
+            id: "voucher_0".to_string(),
+            voucher_id: "voucher".to_string(),
+            consumer: Pubkey::new_unique(),
+            merchant: Pubkey::new_unique(),
+            amount: 100,
+            quantity: 1,
+            status: ConsumptionStatus::Pending,
+            consumed_at,
+            confirmed_at: None,
+            quality_score: None,
+            location: String::new(),
+            notes: String::new(),
+            proof: ConsumptionProof {
+                proof_type: ProofType::Timestamp,
+                proof_data: String::new(),
+                proof_time: consumed_at,
+                proof_signature: String::new(),
+                verified: false,
+            },
+            reward_info: RewardInfo {
+                base_reward: 0,
+                quality_reward: 0,
+                multiplier_reward: 0,
+                total_reward: 0,
+                reward_status: RewardStatus::Pending,
+                reward_distributed_at: None,
+                reward_recipients: Vec::new(),
+            },
+            dispute: None,
+            voucher: Pubkey::new_unique(),
+            escrow_released_at: None,
+        }
+    }
+
+    #[test]
+    fn reset_counters_clears_daily_count_on_new_day() {
+        let mut r = restrictions();
+        r.today_claimed = 5;
+        r.monthly_claimed = 8;
+
+        r.reset_counters(SECONDS_PER_DAY - 1);
+        assert_eq!((r.today_claimed, r.monthly_claimed), (5, 8));
+
+        r.reset_counters(SECONDS_PER_DAY);
+        assert_eq!((r.today_claimed, r.monthly_claimed, r.last_reset_date), (0, 8, 1));
+    }
+
+    #[test]
+    fn reset_counters_clears_monthly_count_on_new_month() {
+        let mut r = restrictions();
+        r.today_claimed = 5;
+        r.monthly_claimed = 8;
+
+        r.reset_counters(SECONDS_PER_MONTH);
+        assert_eq!((r.today_claimed, r.monthly_claimed), (0, 0));
+        assert_eq!(r.last_reset_date, SECONDS_PER_MONTH / SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn check_enforces_claim_window() {
+        let mut r = restrictions();
+        r.claim_start_time = Some(100);
+        r.claim_end_time = Some(200);
+
+        assert!(r.check(1, 0, 99).is_err());
+        assert!(r.check(1, 0, 100).is_ok());
+        assert!(r.check(1, 0, 200).is_ok());
+        assert!(r.check(1, 0, 201).is_err());
+    }
+
+    #[test]
+    fn check_enforces_quantity_limits() {
+        let mut r = restrictions();
+        assert!(r.check(u64::MAX, u64::MAX, 0).is_ok());
+
+        r.per_user_limit = 10;
+        assert!(r.check(4, 6, 0).is_ok());
+        assert!(r.check(5, 6, 0).is_err());
+
+        r.daily_limit = 20;
+        r.today_claimed = 18;
+        assert!(r.check(2, 0, 0).is_ok());
+        assert!(r.check(3, 0, 0).is_err());
+
+        r.today_claimed = 0;
+        r.monthly_limit = 30;
+        r.monthly_claimed = 29;
+        assert!(r.check(2, 0, 0).is_err());
+
+        r.monthly_claimed = 0;
+        r.total_limit = 50;
+        r.current_claimed = 49;
+        assert!(r.check(1, 0, 0).is_ok());
+        assert!(r.check(2, 0, 0).is_err());
+    }
+
+    #[test]
+    fn voucher_claim_consume_deducts_balance() {
+        let mut claim = voucher_claim(100);
+
+        claim.consume(60).unwrap();
+        assert_eq!(claim.balance, 40);
+        assert!(claim.consume(41).is_err());
+        assert_eq!(claim.balance, 40);
+        claim.consume(40).unwrap();
+        assert_eq!(claim.balance, 0);
+        assert_eq!(claim.claimed_amount, 100);
+    }
+
+    #[test]
+    fn voucher_claim_refund_restores_balance() {
+        let mut claim = voucher_claim(100);
+
+        claim.consume(60).unwrap();
+        claim.refund(60).unwrap();
+        assert_eq!(claim.balance, 100);
+        assert_eq!(claim.claimed_amount, 100);
+        assert!(claim.refund(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn voucher_claim_transfer_moves_balance_between_holders() {
+        let mut sender = voucher_claim(100);
+        let mut recipient = VoucherClaim {
+            voucher: Pubkey::default(),
+            consumer: Pubkey::default(),
+            claimed_amount: 0,
+            claim_count: 0,
+            first_claimed_at: 0,
+            last_claimed_at: 0,
+            balance: 0,
+            transferred_in: 0,
+            transferred_out: 0,
+        };
+        let holder = Pubkey::new_unique();
+
+        sender.transfer_out(30).unwrap();
+        recipient.receive_transfer(sender.voucher, holder, 30).unwrap();
+
+        assert_eq!((sender.balance, sender.transferred_out), (70, 30));
+        assert_eq!(sender.claimed_amount, 100);
+        assert_eq!((recipient.balance, recipient.transferred_in), (30, 30));
+        assert_eq!((recipient.claimed_amount, recipient.claim_count), (0, 0));
+        assert_eq!((recipient.voucher, recipient.consumer), (sender.voucher, holder));
+        assert!(!recipient.is_new());
+    }
+
+    #[test]
+    fn voucher_claim_transfer_out_rejects_overdraft() {
+        let mut sender = voucher_claim(100);
+
+        assert!(sender.transfer_out(101).is_err());
+        assert_eq!((sender.balance, sender.transferred_out), (100, 0));
+    }
+
+    #[test]
+    fn voucher_claim_receive_transfer_keeps_existing_holder() {
+        let mut recipient = voucher_claim(10);
+        let (voucher, consumer) = (recipient.voucher, recipient.consumer);
+
+        recipient.receive_transfer(Pubkey::new_unique(), Pubkey::new_unique(), 5).unwrap();
+        assert_eq!((recipient.voucher, recipient.consumer), (voucher, consumer));
+        assert_eq!((recipient.balance, recipient.claimed_amount), (15, 10));
+    }
+
+    #[test]
+    fn confirmed_consumption_dispute_refund_cancels_before_release() {
+        let mut record = consumption_record(0);
+        let arbiter = Pubkey::new_unique();
+
+        record.confirm(8, 100).unwrap();
+        assert_eq!(record.dispute_deadline(), 100 + CONSUMPTION_CONFIRMATION_TIMEOUT);
+
+        record.open_dispute("not delivered".to_string(), 200).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Disputed);
+        assert!(record.release_escrow(record.dispute_deadline() + 1).is_err());
+
+        record.resolve_dispute(arbiter, DisputeOutcome::RefundToVoucher, 300).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Cancelled);
+        let dispute = record.dispute.as_ref().unwrap();
+        assert_eq!((dispute.arbiter, dispute.resolved_at), (Some(arbiter), Some(300)));
+        assert!(record.release_escrow(record.dispute_deadline() + 1).is_err());
+        assert!(!record.escrow_released());
+    }
+
+    #[test]
+    fn confirmed_consumption_dispute_uphold_restores_and_releases_after_window() {
+        let mut record = consumption_record(0);
+
+        record.confirm(8, 100).unwrap();
+        record.open_dispute("late delivery".to_string(), 200).unwrap();
+        record.resolve_dispute(Pubkey::new_unique(), DisputeOutcome::Uphold, 300).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Confirmed);
+
+        // 每条消费记录只能发起一次争议
+        assert!(record.open_dispute("again".to_string(), 400).is_err());
+
+        let deadline = record.dispute_deadline();
+        assert!(record.release_escrow(deadline).is_err());
+        assert!(record.complete().is_err());
+        record.release_escrow(deadline + 1).unwrap();
+        assert!(record.escrow_released());
+        assert!(record.release_escrow(deadline + 2).is_err());
+        record.complete().unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Completed);
+    }
+
+    #[test]
+    fn pending_consumption_dispute_uphold_returns_to_pending() {
+        let mut record = consumption_record(0);
+
+        record.open_dispute("wrong item".to_string(), 10).unwrap();
+        assert!(record.confirm(8, 20).is_err());
+        record.resolve_dispute(Pubkey::new_unique(), DisputeOutcome::Uphold, 30).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Pending);
+        record.confirm(8, 40).unwrap();
+        assert_eq!(record.confirmed_at, Some(40));
+    }
+
+    #[test]
+    fn dispute_rejected_after_deadline() {
+        let mut record = consumption_record(0);
+        assert!(record.open_dispute(String::new(), CONSUMPTION_CONFIRMATION_TIMEOUT + 1).is_err());
+
+        let mut record = consumption_record(0);
+        record.confirm(8, 1_000).unwrap();
+        assert!(record.open_dispute(String::new(), 1_000 + CONSUMPTION_CONFIRMATION_TIMEOUT + 1).is_err());
+        record.open_dispute(String::new(), 1_000 + CONSUMPTION_CONFIRMATION_TIMEOUT).unwrap();
+    }
+
+    #[test]
+    fn consume_credits_rejected_after_expiry() {
+        let mut v = voucher(Some(1_000));
+        v.consume_credits(40, 1_000).unwrap();
+        assert!(v.consume_credits(40, 1_001).is_err());
+        assert_eq!(v.consumed_credits, 40);
+    }
+
+    #[test]
+    fn refund_reactivates_ended_voucher_only_before_expiry() {
+        let mut v = voucher(Some(1_000));
+        v.consume_credits(100, 500).unwrap();
+        assert_eq!(v.status, VoucherStatus::Ended);
+        v.refund_consumption(100, 900).unwrap();
+        assert_eq!(v.status, VoucherStatus::Active);
+
+        v.consume_credits(100, 950).unwrap();
+        v.refund_consumption(100, 1_001).unwrap();
+        assert_eq!(v.status, VoucherStatus::Ended);
+        assert_eq!(v.consumed_credits, 0);
+    }
+
+    #[test]
+    fn refund_does_not_revive_cancelled_or_expired_voucher() {
+        for status in [VoucherStatus::Cancelled, VoucherStatus::Expired] {
+            let mut v = voucher(None);
+            v.consumed_credits = 50;
+            v.outstanding_consumptions = 1;
+            v.status = status.clone();
+            v.refund_consumption(50, 0).unwrap();
+            assert_eq!(v.status, status);
+        }
+    }
+
+    #[test]
+    fn link_upstream_accepts_a_single_upstream_voucher() {
+        let mut v = voucher(None);
+        let upstream = Pubkey::new_unique();
+        let own_producer = v.producer;
+        assert!(v.link_upstream(upstream, &own_producer, 0).is_err());
+        v.link_upstream(upstream, &Pubkey::new_unique(), 10).unwrap();
+        assert_eq!(v.upstream_voucher, Some(upstream));
+        assert!(v.link_upstream(Pubkey::new_unique(), &Pubkey::new_unique(), 20).is_err());
+        assert_eq!(v.upstream_voucher, Some(upstream));
+    }
+
+    #[test]
+    fn auto_settle_waits_for_timeout_and_delay() {
+        let settle_at = CONSUMPTION_CONFIRMATION_TIMEOUT + AUTO_SETTLEMENT_DELAY;
+
+        let mut record = consumption_record(0);
+        assert!(record.auto_settle(&AutoSettlementAction::Confirm, settle_at - 1).is_err());
+        record.auto_settle(&AutoSettlementAction::Confirm, settle_at).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Confirmed);
+        assert_eq!(record.quality_score, Some(DEFAULT_AUTO_SETTLEMENT_QUALITY_SCORE));
+        assert_eq!(record.dispute_deadline(), settle_at + CONSUMPTION_CONFIRMATION_TIMEOUT);
+        assert!(record.release_escrow(settle_at + CONSUMPTION_CONFIRMATION_TIMEOUT).is_err());
+        record.release_escrow(settle_at + CONSUMPTION_CONFIRMATION_TIMEOUT + 1).unwrap();
+
+        let mut record = consumption_record(0);
+        record.auto_settle(&AutoSettlementAction::Cancel, settle_at).unwrap();
+        assert_eq!(record.status, ConsumptionStatus::Cancelled);
+        assert!(record.auto_settle(&AutoSettlementAction::Cancel, settle_at).is_err());
+    }
+}
@@ -9,6 +9,7 @@
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use super::voucher::UserType;
 
 // ================================
 // 用户钱包账户
@@ -60,6 +61,27 @@ pub struct UserWallet {
     
     /// 钱包统计信息
     pub statistics: WalletStatistics,
+    
+    /// 用户类型
+    pub user_type: UserType,
+    
+    /// 已写入的交易记录数（下一条交易记录的序号）
+    pub record_count: u64,
+    
+    /// 最近一次状态变更原因
+    pub status_reason: String,
+    
+    /// 最近一次状态变更时间
+    pub status_updated_at: i64,
+    
+    /// 管理员指定的日转账限额上限（覆盖用户类型默认值）
+    pub transfer_limit_override: Option<u64>,
+
+    /// 尚未关闭的提货券额度账户数
+    pub open_voucher_claims: u64,
+
+    /// 待确认或争议中的消费数
+    pub open_consumptions: u64,
 }
 
 /**
@@ -190,6 +212,36 @@ pub struct TransactionRecord {
     
     /// 交易签名
     pub signature: String,
+    
+    /// 钱包内交易序号
+    pub sequence: u64,
+    
+    /// 奖励类型（仅奖励类交易）
+    pub reward_type: Option<RewardType>,
+}
+
+/**
+ * 交易记录写入参数
+ */
+#[derive(Clone, Debug)]
+pub struct TransactionEntry {
+    /// 交易类型
+    pub transaction_type: TransactionType,
+    
+    /// 交易金额
+    pub amount: u64,
+    
+    /// 代币类型
+    pub token_mint: Pubkey,
+    
+    /// 交易对手方
+    pub counterparty: Option<Pubkey>,
+    
+    /// 奖励类型
+    pub reward_type: Option<RewardType>,
+    
+    /// 交易描述
+    pub description: String,
 }
 
 /**
@@ -420,7 +472,16 @@ impl UserWallet {
         + I64_SIZE * 2     // created_at, updated_at
         + U8_SIZE          // version
         + WalletPermissions::SPACE
-        + WalletStatistics::SPACE;
+        + WalletStatistics::SPACE
+        + 1                // user_type enum
+        + U64_SIZE         // record_count
+        + STRING_PREFIX_SIZE + MAX_STRING_LENGTH // status_reason
+        + I64_SIZE         // status_updated_at
+        + OPTION_FLAG_SIZE + U64_SIZE // transfer_limit_override
+        + U64_SIZE * 2;    // open_voucher_claims, open_consumptions
+
+    /// 当前账户布局版本
+    pub const LAYOUT_VERSION: u8 = 2;
 
     /// 初始化钱包
     pub fn initialize(
@@ -440,20 +501,152 @@ impl UserWallet {
         self.status = WalletStatus::Active;
         self.created_at = current_time;
         self.updated_at = current_time;
-        self.version = 1;
+        self.version = Self::LAYOUT_VERSION;
         self.permissions = WalletPermissions {
             can_receive: true,
             can_send: true,
             can_view_history: true,
-            daily_transfer_limit: 1000000, // 1M tokens
+            daily_transfer_limit: DEFAULT_DAILY_TRANSFER_LIMIT,
             daily_transfer_used: 0,
             last_reset_date: current_time / SECONDS_PER_DAY,
         };
         self.statistics = WalletStatistics::default();
+        self.user_type = UserType::default();
+        self.record_count = 0;
+        self.status_reason = String::new();
+        self.status_updated_at = current_time;
+        self.transfer_limit_override = None;
+        self.open_voucher_claims = 0;
+        self.open_consumptions = 0;
         
         Ok(())
     }
 
+    /// 检查钱包是否允许发送代币（转出、消费）
+    pub fn check_can_send(&self) -> Result<()> {
+        self.check_status(true)?;
+        if !self.permissions.can_send {
+            return Err(crate::errors::SoonShopError::WalletSendDisabled.into());
+        }
+        Ok(())
+    }
+
+    /// 检查钱包是否允许接收代币（转入、获取提货券额度），暂停状态仍可接收
+    pub fn check_can_receive(&self) -> Result<()> {
+        self.check_status(false)?;
+        if !self.permissions.can_receive {
+            return Err(crate::errors::SoonShopError::WalletReceiveDisabled.into());
+        }
+        Ok(())
+    }
+
+    fn check_status(&self, sending: bool) -> Result<()> {
+        match self.status {
+            WalletStatus::Active => Ok(()),
+            WalletStatus::Suspended if !sending => Ok(()),
+            WalletStatus::Suspended => Err(crate::errors::SoonShopError::WalletSuspended.into()),
+            WalletStatus::Frozen => Err(crate::errors::SoonShopError::WalletFrozen.into()),
+            WalletStatus::Closed => Err(crate::errors::SoonShopError::WalletClosed.into()),
+        }
+    }
+
+    /// 冻结钱包（禁止一切代币进出）
+    pub fn freeze(&mut self, reason: String) -> Result<WalletStatus> {
+        if !matches!(self.status, WalletStatus::Active | WalletStatus::Suspended) {
+            return Err(crate::errors::SoonShopError::InvalidWalletStatus.into());
+        }
+        self.set_status(WalletStatus::Frozen, reason)
+    }
+
+    /// 暂停钱包（禁止转出和消费，仍可接收）
+    pub fn suspend(&mut self, reason: String) -> Result<WalletStatus> {
+        if self.status != WalletStatus::Active {
+            return Err(crate::errors::SoonShopError::InvalidWalletStatus.into());
+        }
+        self.set_status(WalletStatus::Suspended, reason)
+    }
+
+    /// 解除冻结或暂停，恢复正常状态
+    pub fn unfreeze(&mut self, reason: String) -> Result<WalletStatus> {
+        if !matches!(self.status, WalletStatus::Frozen | WalletStatus::Suspended) {
+            return Err(crate::errors::SoonShopError::InvalidWalletStatus.into());
+        }
+        self.set_status(WalletStatus::Active, reason)
+    }
+
+    fn set_status(&mut self, status: WalletStatus, reason: String) -> Result<WalletStatus> {
+        if reason.len() > MAX_STRING_LENGTH {
+            return Err(crate::errors::SoonShopError::StringTooLong.into());
+        }
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let previous_status = std::mem::replace(&mut self.status, status);
+        self.status_reason = reason;
+        self.status_updated_at = current_time;
+        self.updated_at = current_time;
+        
+        Ok(previous_status)
+    }
+
+    /// 检查钱包是否可以关闭（仅正常状态、代币余额为零、交易记录和提货券额度账户已全部关闭且没有未结消费）
+    ///
+    /// 交易记录PDA以钱包地址和序号为种子，钱包重新创建后序号从零开始，
+    /// 遗留的交易记录会与新记录地址冲突，因此必须先关闭。
+    pub fn check_closable(&self, token_balance: u64) -> Result<()> {
+        if self.status == WalletStatus::Closed {
+            return Err(crate::errors::SoonShopError::WalletClosed.into());
+        }
+        if self.status != WalletStatus::Active {
+            return Err(crate::errors::SoonShopError::InvalidWalletStatus.into());
+        }
+        if token_balance > 0 {
+            return Err(crate::errors::SoonShopError::WalletNotEmpty.into());
+        }
+        if self.record_count > 0 {
+            return Err(crate::errors::SoonShopError::WalletHasTransactionRecords.into());
+        }
+        if self.open_voucher_claims > 0 {
+            return Err(crate::errors::SoonShopError::WalletHasOpenVoucherClaims.into());
+        }
+        if self.open_consumptions > 0 {
+            return Err(crate::errors::SoonShopError::WalletHasOpenConsumptions.into());
+        }
+        Ok(())
+    }
+
+    /// 关闭钱包：检查可关闭后将状态置为已关闭
+    pub fn mark_closed(&mut self, token_balance: u64) -> Result<()> {
+        self.check_closable(token_balance)?;
+        self.set_status(WalletStatus::Closed, String::new())?;
+        Ok(())
+    }
+
+    /// 登记新开立的提货券额度账户
+    pub fn open_voucher_claim(&mut self) -> Result<()> {
+        self.open_voucher_claims = self.open_voucher_claims
+            .checked_add(1)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// 注销已关闭的提货券额度账户（迁移前开立的账户未计数，因此不会减到负数）
+    pub fn close_voucher_claim(&mut self) {
+        self.open_voucher_claims = self.open_voucher_claims.saturating_sub(1);
+    }
+
+    /// 登记新的待确认消费
+    pub fn open_consumption(&mut self) -> Result<()> {
+        self.open_consumptions = self.open_consumptions
+            .checked_add(1)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// 消费已确认或取消，不再计入未结消费（迁移前的消费未计数，因此不会减到负数）
+    pub fn settle_consumption(&mut self) {
+        self.open_consumptions = self.open_consumptions.saturating_sub(1);
+    }
+
     /// 更新收入
     pub fn add_income(&mut self, amount: u64) -> Result<()> {
         self.total_income = self.total_income
@@ -533,11 +726,45 @@ impl UserWallet {
         }
         
         // 检查是否超过限额
-        if self.permissions.daily_transfer_used + amount > self.permissions.daily_transfer_limit {
-            return Err(crate::errors::SoonShopError::InsufficientFunds.into());
+        let daily_transfer_used = self.permissions.daily_transfer_used
+            .checked_add(amount)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        if daily_transfer_used > self.permissions.daily_transfer_limit {
+            return Err(crate::errors::SoonShopError::DailyTransferLimitExceeded.into());
         }
         
-        self.permissions.daily_transfer_used += amount;
+        self.permissions.daily_transfer_used = daily_transfer_used;
+        Ok(())
+    }
+
+    /// 日转账限额上限：管理员覆盖值优先，否则为用户类型默认值
+    pub fn transfer_limit_ceiling(&self, tier_limit: u64) -> u64 {
+        self.transfer_limit_override.unwrap_or(tier_limit)
+    }
+
+    /// 应用用户类型默认日转账限额（存在管理员覆盖时保持不变）
+    pub fn apply_transfer_limit_tier(&mut self, tier_limit: u64) {
+        if self.transfer_limit_override.is_none() {
+            self.permissions.daily_transfer_limit = tier_limit;
+        }
+    }
+
+    /// 钱包所有者设置日转账限额，只能在上限以内调整
+    pub fn set_daily_transfer_limit(&mut self, limit: u64, tier_limit: u64) -> Result<()> {
+        if limit > self.transfer_limit_ceiling(tier_limit) {
+            return Err(crate::errors::SoonShopError::TransferLimitTooHigh.into());
+        }
+        
+        self.permissions.daily_transfer_limit = limit;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// 管理员覆盖日转账限额上限，传入None时恢复用户类型默认值
+    pub fn override_transfer_limit(&mut self, limit: Option<u64>, tier_limit: u64) -> Result<()> {
+        self.transfer_limit_override = limit;
+        self.permissions.daily_transfer_limit = limit.unwrap_or(tier_limit);
+        self.updated_at = Clock::get()?.unix_timestamp;
         Ok(())
     }
 }
@@ -556,7 +783,9 @@ impl TransactionRecord {
         + 1                        // status enum
         + I64_SIZE                 // timestamp
         + U64_SIZE                 // block_height
-        + STRING_PREFIX_SIZE + 100; // signature
+        + STRING_PREFIX_SIZE + 100 // signature
+        + U64_SIZE                 // sequence
+        + OPTION_FLAG_SIZE + 1;    // reward_type
 
     /// 初始化交易记录
     pub fn initialize(
@@ -600,6 +829,96 @@ impl TransactionRecord {
         self.status = TransactionStatus::Failed;
         Ok(())
     }
+
+    /// 以钱包当前记录序号写入一条已完成的交易记录，并推进钱包记录序号
+    pub fn record(&mut self, wallet: &mut UserWallet, entry: TransactionEntry) -> Result<()> {
+        let sequence = wallet.record_count;
+        
+        self.initialize(
+            format!("tx_{}", sequence),
+            wallet.owner,
+            entry.transaction_type,
+            entry.amount,
+            entry.token_mint,
+            entry.counterparty,
+            entry.description,
+            String::new(),
+        )?;
+        self.sequence = sequence;
+        self.reward_type = entry.reward_type;
+        self.complete(String::new())?;
+        
+        wallet.record_count = sequence
+            .checked_add(1)
+            .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        
+        Ok(())
+    }
+
+    /// 是否在查询时间范围内
+    // Option::is_none_or 需要较新的Rust版本，Solana工具链上使用map_or
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn in_time_range(&self, start_time: Option<i64>, end_time: Option<i64>) -> bool {
+        start_time.map_or(true, |start| self.timestamp >= start)
+            && end_time.map_or(true, |end| self.timestamp <= end)
+    }
+
+    /// 转换为收入记录（非收入类交易返回None）
+    pub fn as_income_record(&self) -> Option<IncomeRecord> {
+        let source = match self.transaction_type {
+            TransactionType::Income => IncomeSource::Other,
+            TransactionType::TransferReceived => IncomeSource::Transfer,
+            TransactionType::VoucherClaim => IncomeSource::VoucherReward,
+            TransactionType::TokenMint => IncomeSource::TokenMint,
+            _ => return None,
+        };
+        
+        Some(IncomeRecord {
+            amount: self.amount,
+            source,
+            timestamp: self.timestamp,
+            transaction_id: self.id.clone(),
+            note: self.description.clone(),
+        })
+    }
+
+    /// 转换为支出记录（非支出类交易返回None）
+    pub fn as_expense_record(&self) -> Option<ExpenseRecord> {
+        let category = match self.transaction_type {
+            TransactionType::Expense => ExpenseCategory::Other,
+            TransactionType::TransferSent => ExpenseCategory::Transfer,
+            TransactionType::VoucherConsumption => ExpenseCategory::VoucherConsumption,
+            TransactionType::PlatformFee => ExpenseCategory::PlatformFee,
+            TransactionType::TokenBurn => ExpenseCategory::TokenBurn,
+            _ => return None,
+        };
+        
+        Some(ExpenseRecord {
+            amount: self.amount,
+            category,
+            timestamp: self.timestamp,
+            transaction_id: self.id.clone(),
+            note: self.description.clone(),
+        })
+    }
+
+    /// 转换为奖励记录（非奖励类交易返回None）
+    pub fn as_reward_record(&self) -> Option<RewardRecord> {
+        let reward_type = match (&self.transaction_type, &self.reward_type) {
+            (TransactionType::Reward | TransactionType::MultiplierReward, Some(reward_type)) => reward_type.clone(),
+            (TransactionType::MultiplierReward, None) => RewardType::MultiplierReward,
+            _ => return None,
+        };
+        
+        Some(RewardRecord {
+            amount: self.amount,
+            reward_type,
+            timestamp: self.timestamp,
+            transaction_id: self.id.clone(),
+            source: self.counterparty.map(|key| key.to_string()).unwrap_or_default(),
+            note: self.description.clone(),
+        })
+    }
 }
 
 impl WalletPermissions {
@@ -0,0 +1,170 @@
+/**
+ * SoonShop核心智能合约职工名册状态模块
+ *
+ * 本模块定义了企业职工分红相关的状态结构体，包括：
+ * - 职工名册信息
+ * - 职工分红权重
+ */
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+// ================================
+// 职工名册账户
+// ================================
+
+/**
+ * 职工名册信息
+ *
+ * 存储生产者的职工列表及其分红权重，倍增奖励中的职工份额按权重分配
+ */
+#[account]
+#[derive(Debug)]
+pub struct WorkerRoster {
+    /// 生产者公钥
+    pub producer: Pubkey,
+
+    /// 职工列表
+    pub workers: Vec<WorkerShare>,
+
+    /// 权重总和
+    pub total_weight: u64,
+
+    /// 创建时间
+    pub created_at: i64,
+
+    /// 更新时间
+    pub updated_at: i64,
+}
+
+/**
+ * 职工分红权重
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct WorkerShare {
+    /// 职工公钥
+    pub worker: Pubkey,
+
+    /// 分红权重
+    pub weight: u32,
+}
+
+// ================================
+// 实现
+// ================================
+
+impl WorkerRoster {
+    /// 计算账户所需空间
+    pub const SPACE: usize = ACCOUNT_DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE                // producer
+        + VEC_PREFIX_SIZE + MAX_WORKERS_PER_ROSTER * WorkerShare::SPACE // workers
+        + U64_SIZE                   // total_weight
+        + I64_SIZE * 2;              // created_at, updated_at
+
+    /// 初始化职工名册
+    pub fn initialize(&mut self, producer: Pubkey, workers: Vec<WorkerShare>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.producer = producer;
+        self.created_at = current_time;
+        self.set_workers(workers)
+    }
+
+    /// 更新职工列表
+    pub fn set_workers(&mut self, workers: Vec<WorkerShare>) -> Result<()> {
+        require!(
+            workers.len() <= MAX_WORKERS_PER_ROSTER,
+            crate::errors::SoonShopError::ArrayTooLong
+        );
+
+        let mut total_weight: u64 = 0;
+        for (index, share) in workers.iter().enumerate() {
+            // 权重必须为正且职工不能重复
+            if share.weight == 0 || workers[..index].iter().any(|w| w.worker == share.worker) {
+                return Err(crate::errors::SoonShopError::InvalidWorkerShareRatio.into());
+            }
+            total_weight = total_weight
+                .checked_add(share.weight as u64)
+                .ok_or(crate::errors::SoonShopError::MathOverflow)?;
+        }
+
+        self.workers = workers;
+        self.total_weight = total_weight;
+        self.updated_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// 是否有可分红的职工
+    pub fn has_workers(&self) -> bool {
+        !self.workers.is_empty() && self.total_weight > 0
+    }
+
+    /// 按权重计算每位职工的分红金额（舍入余数不分配）
+    pub fn calculate_shares(&self, amount: u64) -> Result<Vec<u64>> {
+        if !self.has_workers() {
+            return Ok(Vec::new());
+        }
+
+        self.workers
+            .iter()
+            .map(|share| {
+                let portion = (amount as u128)
+                    .checked_mul(share.weight as u128)
+                    .ok_or(crate::errors::SoonShopError::MathOverflow)?
+                    / self.total_weight as u128;
+                Ok(portion as u64)
+            })
+            .collect()
+    }
+}
+
+impl WorkerShare {
+    pub const SPACE: usize = PUBKEY_SIZE + U32_SIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster(weights: &[u32]) -> WorkerRoster {
+        let workers: Vec<WorkerShare> = weights
+            .iter()
+            .map(|&weight| WorkerShare { worker: Pubkey::new_unique(), weight })
+            .collect();
+        WorkerRoster {
+            producer: Pubkey::new_unique(),
+            total_weight: weights.iter().map(|&w| w as u64).sum(),
+            workers,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn calculate_shares_splits_by_weight() {
+        let shares = roster(&[1, 3]).calculate_shares(1_000).unwrap();
+        assert_eq!(shares, vec![250, 750]);
+    }
+
+    #[test]
+    fn calculate_shares_rounds_down_and_keeps_remainder() {
+        let shares = roster(&[1, 1, 1]).calculate_shares(100).unwrap();
+        assert_eq!(shares, vec![33, 33, 33]);
+
+        let shares = roster(&[2, 3]).calculate_shares(7).unwrap();
+        assert_eq!(shares, vec![2, 4]);
+        assert!(shares.iter().sum::<u64>() <= 7);
+    }
+
+    #[test]
+    fn calculate_shares_handles_large_amounts() {
+        let shares = roster(&[u32::MAX, u32::MAX]).calculate_shares(u64::MAX).unwrap();
+        assert_eq!(shares, vec![u64::MAX / 2, u64::MAX / 2]);
+    }
+
+    #[test]
+    fn calculate_shares_without_workers_is_empty() {
+        assert!(roster(&[]).calculate_shares(1_000).unwrap().is_empty());
+    }
+}